@@ -0,0 +1,257 @@
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::Millisecond;
+
+impl Millisecond {
+    /// Adds two instances, carrying across every field.
+    /// Returns `None` when the result does not fit into a `Millisecond`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let x = Millisecond::from_millis(59_600);
+    /// let y = Millisecond::from_millis(400);
+    /// assert_eq!(x.checked_add(y), Some(Millisecond::from_minutes(1)));
+    /// assert_eq!(Millisecond::MAX.checked_add(y), None);
+    /// ```
+    pub fn checked_add(self, rhs: Millisecond) -> Option<Millisecond> {
+        self.as_nanos()
+            .checked_add(rhs.as_nanos())
+            .and_then(|x| Self::try_from_nanos(x).ok())
+    }
+
+    /// Subtracts `rhs` from the instance, borrowing across every field.
+    /// Returns `None` when `rhs` is larger than the instance.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let x = Millisecond::from_minutes(1);
+    /// let y = Millisecond::from_millis(400);
+    /// assert_eq!(x.checked_sub(y), Some(Millisecond::from_millis(59_600)));
+    /// assert_eq!(y.checked_sub(x), None);
+    /// ```
+    pub fn checked_sub(self, rhs: Millisecond) -> Option<Millisecond> {
+        self.as_nanos()
+            .checked_sub(rhs.as_nanos())
+            .and_then(|x| Self::try_from_nanos(x).ok())
+    }
+
+    /// Multiplies the instance by `rhs`, carrying across every field.
+    /// Returns `None` when the result does not fit into a `Millisecond`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let x = Millisecond::from_secs(90);
+    /// assert_eq!(x.checked_mul(2), Some(Millisecond::from_minutes(3)));
+    /// assert_eq!(Millisecond::MAX.checked_mul(2), None);
+    /// ```
+    pub fn checked_mul(self, rhs: u64) -> Option<Millisecond> {
+        self.as_nanos()
+            .checked_mul(rhs as u128)
+            .and_then(|x| Self::try_from_nanos(x).ok())
+    }
+
+    /// Divides the instance by `rhs`, down to nanosecond precision.
+    /// Returns `None` when `rhs` is zero.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let x = Millisecond::from_minutes(3);
+    /// assert_eq!(x.checked_div(2), Some(Millisecond::from_secs(90)));
+    /// assert_eq!(x.checked_div(0), None);
+    /// ```
+    pub fn checked_div(self, rhs: u64) -> Option<Millisecond> {
+        self.as_nanos()
+            .checked_div(rhs as u128)
+            .and_then(|x| Self::try_from_nanos(x).ok())
+    }
+
+    /// Adds two instances, returning `Millisecond::MAX` on overflow.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let x = Millisecond::from_secs(1);
+    /// assert_eq!(Millisecond::MAX.saturating_add(x), Millisecond::MAX);
+    /// ```
+    pub fn saturating_add(self, rhs: Millisecond) -> Millisecond {
+        self.checked_add(rhs).unwrap_or(Millisecond::MAX)
+    }
+
+    /// Subtracts `rhs` from the instance, returning `Millisecond::ZERO` on underflow.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let x = Millisecond::from_secs(1);
+    /// assert_eq!(Millisecond::ZERO.saturating_sub(x), Millisecond::ZERO);
+    /// ```
+    pub fn saturating_sub(self, rhs: Millisecond) -> Millisecond {
+        self.checked_sub(rhs).unwrap_or(Millisecond::ZERO)
+    }
+
+    /// Multiplies the instance by `rhs`, returning `Millisecond::MAX` on overflow.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::MAX.saturating_mul(2), Millisecond::MAX);
+    /// ```
+    pub fn saturating_mul(self, rhs: u64) -> Millisecond {
+        self.checked_mul(rhs).unwrap_or(Millisecond::MAX)
+    }
+}
+
+impl Add for Millisecond {
+    type Output = Millisecond;
+
+    fn add(self, rhs: Millisecond) -> Millisecond {
+        self.checked_add(rhs)
+            .expect("overflow when adding Millisecond")
+    }
+}
+
+impl AddAssign for Millisecond {
+    fn add_assign(&mut self, rhs: Millisecond) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Millisecond {
+    type Output = Millisecond;
+
+    fn sub(self, rhs: Millisecond) -> Millisecond {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting Millisecond")
+    }
+}
+
+impl SubAssign for Millisecond {
+    fn sub_assign(&mut self, rhs: Millisecond) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u64> for Millisecond {
+    type Output = Millisecond;
+
+    fn mul(self, rhs: u64) -> Millisecond {
+        self.checked_mul(rhs)
+            .expect("overflow when multiplying Millisecond by scalar")
+    }
+}
+
+impl MulAssign<u64> for Millisecond {
+    fn mul_assign(&mut self, rhs: u64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<u64> for Millisecond {
+    type Output = Millisecond;
+
+    fn div(self, rhs: u64) -> Millisecond {
+        self.checked_div(rhs)
+            .expect("divide by zero error when dividing Millisecond by scalar")
+    }
+}
+
+impl DivAssign<u64> for Millisecond {
+    fn div_assign(&mut self, rhs: u64) {
+        *self = *self / rhs;
+    }
+}
+
+impl Sum for Millisecond {
+    fn sum<I: Iterator<Item = Millisecond>>(iter: I) -> Millisecond {
+        iter.fold(Millisecond::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a Millisecond> for Millisecond {
+    fn sum<I: Iterator<Item = &'a Millisecond>>(iter: I) -> Millisecond {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_carry_when_adding() {
+        let x = Millisecond {
+            years: 0,
+            days: 364,
+            hours: 23,
+            minutes: 59,
+            seconds: 59,
+            millis: 999,
+            micros: 999,
+            nanos: 999,
+        };
+        assert_eq!(x + Millisecond::from_nanos(1), Millisecond::from_years(1));
+
+        let mut x = Millisecond::from_millis(999);
+        x += Millisecond::from_millis(2);
+        assert_eq!(x, Millisecond::from_millis(1_001));
+    }
+    #[test]
+    fn should_borrow_when_subtracting() {
+        let x = Millisecond::from_years(1) - Millisecond::from_nanos(1);
+        assert_eq!(
+            x,
+            Millisecond {
+                years: 0,
+                days: 364,
+                hours: 23,
+                minutes: 59,
+                seconds: 59,
+                millis: 999,
+                micros: 999,
+                nanos: 999,
+            }
+        );
+        assert_eq!(x.checked_sub(Millisecond::from_years(1)), None);
+    }
+    #[test]
+    fn should_multiply_and_divide() {
+        let x = Millisecond::from_millis(1_500);
+        assert_eq!(x * 3, Millisecond::from_millis(4_500));
+        assert_eq!(x / 4, Millisecond::from_micros(375_000));
+        assert_eq!(Millisecond::from_nanos(10) / 3, Millisecond::from_nanos(3));
+        assert_eq!(Millisecond::MAX / 1, Millisecond::MAX);
+    }
+    #[test]
+    fn should_sum_timings() {
+        let timings = [
+            Millisecond::from_millis(800),
+            Millisecond::from_millis(700),
+            Millisecond::from_micros(1),
+        ];
+        let expected = Millisecond::from_micros(1_500_001);
+        assert_eq!(timings.iter().sum::<Millisecond>(), expected);
+        assert_eq!(timings.into_iter().sum::<Millisecond>(), expected);
+        assert_eq!([].iter().sum::<Millisecond>(), Millisecond::ZERO);
+    }
+    #[test]
+    fn should_normalize_operands() {
+        let x = Millisecond {
+            seconds: 90,
+            millis: 2500,
+            ..Default::default()
+        };
+        assert_eq!(x + Millisecond::ZERO, Millisecond::from_millis(92_500));
+    }
+    #[test]
+    fn should_saturate() {
+        let one = Millisecond::from_nanos(1);
+        assert_eq!(Millisecond::MAX.checked_add(one), None);
+        assert_eq!(Millisecond::MAX.saturating_add(one), Millisecond::MAX);
+        assert_eq!(Millisecond::ZERO.saturating_sub(one), Millisecond::ZERO);
+        assert_eq!(Millisecond::MAX.saturating_mul(u64::MAX), Millisecond::MAX);
+        assert_eq!(Millisecond::MAX.checked_mul(u64::MAX), None);
+    }
+    #[test]
+    #[should_panic(expected = "overflow when adding Millisecond")]
+    fn should_panic_on_add_overflow() {
+        let _ = Millisecond::MAX + Millisecond::from_nanos(1);
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::formatter::with_nanos_fraction;
+use crate::Millisecond;
+
+/// Options to customize the colon (clock) notation of a `Millisecond`, e.g. `1:02:03.456`.
+/// ## Example
+/// ```rust
+/// use millisecond::{ColonOptions, Millisecond};
+///
+/// let ms = Millisecond::from_millis(93_784_500);
+/// assert_eq!(ms.to_colon_string(), "1:02:03:04.5");
+///
+/// let opts = ColonOptions::new().days(false).pad_leading(true).fraction_digits(3);
+/// assert_eq!(ms.format_colon_with(&opts), "26:03:04.500");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColonOptions {
+    pub(crate) days: bool,
+    pub(crate) always_hours: bool,
+    pub(crate) pad_leading: bool,
+    pub(crate) fraction_digits: Option<u8>,
+}
+
+impl Default for ColonOptions {
+    fn default() -> Self {
+        Self {
+            days: true,
+            always_hours: false,
+            pad_leading: false,
+            fraction_digits: None,
+        }
+    }
+}
+
+impl ColonOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Leads with days as `d:hh:mm:ss` when the value has any, which is the default.
+    /// When disabled, days (and years, as 365 days) are counted into the hours instead.
+    pub fn days(mut self, days: bool) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Shows the hours even when they are zero, e.g. `0:01:05` instead of `1:05`.
+    pub fn always_hours(mut self, always: bool) -> Self {
+        self.always_hours = always;
+        self
+    }
+
+    /// Zero-pads the leading unit to two digits, e.g. `01:02:03` instead of `1:02:03`.
+    pub fn pad_leading(mut self, pad: bool) -> Self {
+        self.pad_leading = pad;
+        self
+    }
+
+    /// Writes the fraction of seconds with exactly `digits` digits, truncating the rest.
+    /// By default, as many digits as needed are written.
+    pub fn fraction_digits(mut self, digits: u8) -> Self {
+        self.fraction_digits = Some(digits);
+        self
+    }
+}
+
+impl Millisecond {
+    /// Formats the instance in colon notation with the default options.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::from_millis(3_723_456).to_colon_string(), "1:02:03.456");
+    /// assert_eq!(Millisecond::from_secs(5).to_colon_string(), "0:05");
+    /// ```
+    pub fn to_colon_string(&self) -> String {
+        self.format_colon_with(&ColonOptions::default())
+    }
+
+    /// Formats the instance in colon notation according to `options`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{ColonOptions, Millisecond};
+    /// let ms = Millisecond::from_secs(65);
+    /// let opts = ColonOptions::new().always_hours(true).pad_leading(true);
+    /// assert_eq!(ms.format_colon_with(&opts), "00:01:05");
+    /// ```
+    pub fn format_colon_with(&self, options: &ColonOptions) -> String {
+        let ms = self.checked_normalize().unwrap_or(Millisecond::MAX);
+        let days = ms.years as u128 * 365 + ms.days as u128;
+        let nanos = (ms.millis as u32 * 1_000 + ms.micros as u32) * 1_000 + ms.nanos as u32;
+        let seconds = with_nanos_fraction(
+            &format!("{:02}", ms.seconds),
+            nanos,
+            options.fraction_digits,
+        );
+
+        let leading = |x: u128| {
+            if options.pad_leading {
+                format!("{x:02}")
+            } else {
+                format!("{x}")
+            }
+        };
+        if options.days && days > 0 {
+            format!(
+                "{}:{:02}:{:02}:{seconds}",
+                leading(days),
+                ms.hours,
+                ms.minutes
+            )
+        } else if options.always_hours || days > 0 || ms.hours > 0 {
+            let hours = days * 24 + ms.hours as u128;
+            format!("{}:{:02}:{seconds}", leading(hours), ms.minutes)
+        } else {
+            format!("{}:{seconds}", leading(ms.minutes as u128))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_colon_notation() {
+        let cases = [
+            (0, "0:00"),
+            (5_000, "0:05"),
+            (65_000, "1:05"),
+            (95_500, "1:35.5"),
+            (3_723_456, "1:02:03.456"),
+            (86_400_000, "1:00:00:00"),
+            (31_536_000_000 + 1_000, "365:00:00:01"),
+        ];
+        for (k, expected) in cases {
+            assert_eq!(
+                Millisecond::from_millis(k).to_colon_string(),
+                expected,
+                "{k}"
+            );
+        }
+        assert_eq!(
+            Millisecond::from_nanos(1_000_000_007).to_colon_string(),
+            "0:01.000000007"
+        );
+    }
+    #[test]
+    fn should_format_colon_notation_with_options() {
+        let ms = Millisecond::from_millis(93_784_567);
+        let cases = [
+            (ColonOptions::new(), "1:02:03:04.567"),
+            (ColonOptions::new().days(false), "26:03:04.567"),
+            (ColonOptions::new().pad_leading(true), "01:02:03:04.567"),
+            (ColonOptions::new().fraction_digits(0), "1:02:03:04"),
+            (ColonOptions::new().fraction_digits(1), "1:02:03:04.5"),
+            (ColonOptions::new().fraction_digits(4), "1:02:03:04.5670"),
+        ];
+        for (opts, expected) in cases {
+            assert_eq!(ms.format_colon_with(&opts), expected, "{opts:?}");
+        }
+
+        let ms = Millisecond::from_millis(4_500);
+        let opts = ColonOptions::new().always_hours(true);
+        assert_eq!(ms.format_colon_with(&opts), "0:00:04.5");
+        assert_eq!(ms.format_colon_with(&opts.pad_leading(true)), "00:00:04.5");
+    }
+}
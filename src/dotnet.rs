@@ -0,0 +1,356 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parser::{add_nanos, to_unsigned, Scanner};
+use crate::{Error, Millisecond, SignedMillisecond, Unit};
+
+/// The length of a .NET tick in nanoseconds.
+const NANOS_PER_TICK: u128 = 100;
+
+impl Millisecond {
+    /// Creates a Millisecond instance using the provided .NET ticks of 100 nanoseconds,
+    /// which is the precision of `TimeSpan.Ticks`. Returns `Error::Negative` for negative
+    /// ticks, see `SignedMillisecond::from_dotnet_ticks` for them.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Error, Millisecond};
+    /// let ms = Millisecond::try_from_dotnet_ticks(15_000_000);
+    /// assert_eq!(ms, Ok(Millisecond::from_millis(1_500)));
+    /// assert_eq!(Millisecond::try_from_dotnet_ticks(-1), Err(Error::Negative));
+    /// ```
+    pub fn try_from_dotnet_ticks(ticks: i64) -> Result<Self, Error> {
+        Millisecond::try_from(SignedMillisecond::from_dotnet_ticks(ticks))
+    }
+
+    /// Returns the total number of whole .NET ticks of 100 nanoseconds, the same as
+    /// `TimeSpan.Ticks`, truncating what is below a tick. Returns `Error::Overflow` when
+    /// the value is beyond `TimeSpan.MaxValue`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Error, Millisecond};
+    /// let ms = Millisecond::from_nanos(1_500_000_050);
+    /// assert_eq!(ms.to_dotnet_ticks(), Ok(15_000_000));
+    /// assert_eq!(Millisecond::MAX.to_dotnet_ticks(), Err(Error::Overflow));
+    /// ```
+    pub fn to_dotnet_ticks(&self) -> Result<i64, Error> {
+        i64::try_from(self.as_nanos() / NANOS_PER_TICK).map_err(|_| Error::Overflow)
+    }
+
+    /// Formats the instance the same as the constant (`c`) format of a .NET `TimeSpan`,
+    /// i.e. `[d.]hh:mm:ss[.fffffff]`, e.g. `1.05:10:48.1234567`. Years are written as
+    /// 365 days, and what is below a tick of 100 nanoseconds is truncated.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_nanos(105_048_123_456_789);
+    /// assert_eq!(ms.to_dotnet_timespan(), "1.05:10:48.1234567");
+    /// assert_eq!(Millisecond::from_secs(90).to_dotnet_timespan(), "00:01:30");
+    /// ```
+    pub fn to_dotnet_timespan(&self) -> String {
+        write_timespan(false, self)
+    }
+
+    /// Parses a duration in the constant (`c`) format of a .NET `TimeSpan`, i.e.
+    /// `[d.]hh:mm[:ss[.fffffff]]`, or a number of days alone, the same as
+    /// `TimeSpan.ParseExact(s, "c", null)`. Hours, minutes and seconds out of their
+    /// range are rejected with `ParseErrorKind::ComponentOutOfRange`, and negative
+    /// values with `ParseErrorKind::Negative`, see `SignedMillisecond::from_dotnet_timespan`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, ParseErrorKind};
+    /// let ms = Millisecond::from_dotnet_timespan("1.05:10:48.1234567").unwrap();
+    /// assert_eq!(ms, Millisecond::from_nanos(105_048_123_456_700));
+    ///
+    /// let err = Millisecond::from_dotnet_timespan("00:60:00").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::ComponentOutOfRange);
+    /// assert_eq!(err.offset(), 3);
+    /// ```
+    pub fn from_dotnet_timespan(input: &str) -> Result<Self, ParseError> {
+        parse_timespan(input).and_then(to_unsigned)
+    }
+}
+
+impl SignedMillisecond {
+    /// Creates a SignedMillisecond instance using the provided .NET ticks of 100
+    /// nanoseconds, the same as `TimeSpan.FromTicks`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::SignedMillisecond;
+    /// let ms = SignedMillisecond::from_dotnet_ticks(-15_000_000);
+    /// assert_eq!(ms, SignedMillisecond::from_millis_i128(-1_500));
+    /// ```
+    pub fn from_dotnet_ticks(ticks: i64) -> Self {
+        // `i64` ticks are far below the largest `Millisecond`
+        SignedMillisecond::from_nanos_i128(ticks as i128 * NANOS_PER_TICK as i128)
+    }
+
+    /// Returns the total number of whole .NET ticks of 100 nanoseconds, the same as
+    /// `TimeSpan.Ticks`, truncating what is below a tick toward zero. Returns
+    /// `Error::Overflow` when the value is beyond the range of a `TimeSpan`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::SignedMillisecond;
+    /// let ms = SignedMillisecond::from_nanos_i128(-1_500_000_050);
+    /// assert_eq!(ms.to_dotnet_ticks(), Ok(-15_000_000));
+    /// ```
+    pub fn to_dotnet_ticks(&self) -> Result<i64, Error> {
+        i64::try_from(self.as_nanos() / NANOS_PER_TICK as i128).map_err(|_| Error::Overflow)
+    }
+
+    /// Formats the instance the same as the constant (`c`) format of a .NET `TimeSpan`,
+    /// i.e. `[-][d.]hh:mm:ss[.fffffff]`. See `Millisecond::to_dotnet_timespan`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::SignedMillisecond;
+    /// let ms = SignedMillisecond::from_nanos_i128(-105_048_123_456_789);
+    /// assert_eq!(ms.to_dotnet_timespan(), "-1.05:10:48.1234567");
+    /// ```
+    pub fn to_dotnet_timespan(&self) -> String {
+        write_timespan(self.is_negative(), &self.magnitude())
+    }
+
+    /// Parses a duration in the constant (`c`) format of a .NET `TimeSpan`, i.e.
+    /// `[-][d.]hh:mm[:ss[.fffffff]]`. See `Millisecond::from_dotnet_timespan`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, SignedMillisecond};
+    /// let ms = SignedMillisecond::from_dotnet_timespan("-00:01:30").unwrap();
+    /// assert_eq!(ms, SignedMillisecond::new(true, Millisecond::from_secs(90)));
+    /// ```
+    pub fn from_dotnet_timespan(input: &str) -> Result<Self, ParseError> {
+        parse_timespan(input).map(|(ms, _)| ms)
+    }
+}
+
+/// Writes `ms` in the constant format of a `TimeSpan`, with a `-` when `negative`.
+fn write_timespan(negative: bool, ms: &Millisecond) -> String {
+    let ticks = ms.as_nanos() / NANOS_PER_TICK;
+    let fraction = ticks % (Unit::Seconds.nanos() / NANOS_PER_TICK);
+    let seconds = ms.as_secs();
+    let days = seconds / 86_400;
+
+    let mut text = String::new();
+    // what is below a tick is truncated toward zero, so it is never `-00:00:00`
+    if negative && ticks > 0 {
+        text += "-";
+    }
+    if days > 0 {
+        text += &format!("{days}.");
+    }
+    text += &format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3_600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    if fraction > 0 {
+        text += &format!(".{fraction:07}");
+    }
+    text
+}
+
+/// Parses a `TimeSpan` in the constant format, along with the offset of its sign.
+fn parse_timespan(input: &str) -> Result<(SignedMillisecond, usize), ParseError> {
+    let mut s = Scanner::new(input);
+    s.skip_whitespace();
+    if s.is_end() {
+        return Err(ParseError::new(ParseErrorKind::Empty, s.pos()));
+    }
+    let sign = s.pos();
+    let negative = s.eat("-");
+
+    let start = s.pos();
+    let first = component(&mut s, u128::MAX)?;
+    // the hours along with their offset, when there is a time of day
+    let (days, hours) = if s.eat(".") {
+        let hours_start = s.pos();
+        let hours = component(&mut s, 23)?;
+        expect(&mut s, ":")?;
+        (first, Some((hours, hours_start)))
+    } else if s.eat(":") {
+        if first > 23 {
+            return Err(ParseError::new(ParseErrorKind::ComponentOutOfRange, start));
+        }
+        (0, Some((first, start)))
+    } else {
+        (first, None)
+    };
+
+    let mut nanos = add_nanos(0, days.checked_mul(Unit::Days.nanos()), start)?;
+    if let Some((hours, hours_start)) = hours {
+        nanos = add_nanos(nanos, Some(hours * Unit::Hours.nanos()), hours_start)?;
+        let minutes_start = s.pos();
+        let minutes = component(&mut s, 59)?;
+        nanos = add_nanos(nanos, Some(minutes * Unit::Minutes.nanos()), minutes_start)?;
+        if s.eat(":") {
+            let seconds_start = s.pos();
+            let seconds = component(&mut s, 59)?;
+            nanos = add_nanos(nanos, Some(seconds * Unit::Seconds.nanos()), seconds_start)?;
+            if s.eat(".") {
+                let digits_start = s.pos();
+                let digits = s.take_while(|c| c.is_ascii_digit());
+                if digits.is_empty() || digits.len() > 7 {
+                    let kind = ParseErrorKind::InvalidNumber;
+                    return Err(ParseError::new(kind, digits_start));
+                }
+                let scale = 10u128.pow(7 - digits.len() as u32);
+                let ticks = digits.parse::<u128>().unwrap_or(0) * scale;
+                nanos = add_nanos(nanos, Some(ticks * NANOS_PER_TICK), digits_start)?;
+            }
+        }
+    }
+    s.skip_whitespace();
+    if !s.is_end() {
+        return Err(ParseError::new(ParseErrorKind::UnexpectedToken, s.pos()));
+    }
+
+    Ok((
+        SignedMillisecond::new(negative, Millisecond::from_nanos(nanos)),
+        sign,
+    ))
+}
+
+/// Consumes a number which is at most `max`.
+fn component(s: &mut Scanner, max: u128) -> Result<u128, ParseError> {
+    let start = s.pos();
+    let value = match s.take_while(|c| c.is_ascii_digit()) {
+        "" => return Err(ParseError::new(ParseErrorKind::InvalidNumber, start)),
+        digits => digits
+            .parse()
+            .map_err(|_| ParseError::new(ParseErrorKind::Overflow, start))?,
+    };
+    if value > max {
+        return Err(ParseError::new(ParseErrorKind::ComponentOutOfRange, start));
+    }
+    Ok(value)
+}
+
+fn expect(s: &mut Scanner, text: &str) -> Result<(), ParseError> {
+    if !s.eat(text) {
+        return Err(ParseError::new(ParseErrorKind::UnexpectedToken, s.pos()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_dotnet_ticks() {
+        assert_eq!(Millisecond::try_from_dotnet_ticks(0), Ok(Millisecond::ZERO));
+        assert_eq!(
+            Millisecond::try_from_dotnet_ticks(1),
+            Ok(Millisecond::from_nanos(100))
+        );
+        assert_eq!(Millisecond::try_from_dotnet_ticks(-1), Err(Error::Negative));
+        assert_eq!(Millisecond::from_nanos(199).to_dotnet_ticks(), Ok(1));
+        let ms = Millisecond::from_nanos(i64::MAX as u128 * 100 + 100);
+        assert_eq!(ms.to_dotnet_ticks(), Err(Error::Overflow));
+        // every `i64` of ticks round-trips, the same as `TimeSpan`
+        for ticks in [i64::MIN, -1, 0, 1, i64::MAX] {
+            let ms = SignedMillisecond::from_dotnet_ticks(ticks);
+            assert_eq!(ms.as_nanos(), ticks as i128 * 100);
+            assert_eq!(ms.to_dotnet_ticks(), Ok(ticks), "{ticks}");
+        }
+        let ms = SignedMillisecond::from_nanos_i128(-199);
+        assert_eq!(ms.to_dotnet_ticks(), Ok(-1));
+        let ms = SignedMillisecond::from_nanos_i128(i64::MIN as i128 * 100 - 100);
+        assert_eq!(ms.to_dotnet_ticks(), Err(Error::Overflow));
+    }
+    #[test]
+    fn should_format_dotnet_timespan() {
+        let cases = [
+            (0, "00:00:00"),
+            (1, "00:00:00.0000001"),
+            (15_000_000, "00:00:01.5000000"),
+            (600_000_000, "00:01:00"),
+            (863_999_999_999, "23:59:59.9999999"),
+            (864_000_000_000, "1.00:00:00"),
+            (1_050_481_234_567, "1.05:10:48.1234567"),
+            (i64::MAX, "10675199.02:48:05.4775807"),
+        ];
+        for (k, v) in cases {
+            let ms = Millisecond::try_from_dotnet_ticks(k).unwrap();
+            assert_eq!(ms.to_dotnet_timespan(), v, "{k}");
+            assert_eq!(Millisecond::from_dotnet_timespan(v), Ok(ms), "{v}");
+        }
+        assert_eq!(
+            Millisecond::from_years(1).to_dotnet_timespan(),
+            "365.00:00:00"
+        );
+        assert_eq!(Millisecond::from_nanos(99).to_dotnet_timespan(), "00:00:00");
+    }
+    #[test]
+    fn should_parse_dotnet_timespan() {
+        let cases = [
+            ("5", Millisecond::from_days(5)),
+            ("1:2", Millisecond::from_minutes(62)),
+            ("01:02:03", Millisecond::from_secs(3_723)),
+            ("2.01:02", Millisecond::from_minutes(2 * 1_440 + 62)),
+            ("00:00:01.5", Millisecond::from_millis(1_500)),
+            (" 00:00:01.0000001 ", Millisecond::from_nanos(1_000_000_100)),
+            ("-00:00:00", Millisecond::ZERO),
+            (
+                "6733061586903986339839.23:59:59.9999999",
+                Millisecond::MAX - Millisecond::from_nanos(99),
+            ),
+        ];
+        for (k, v) in cases {
+            assert_eq!(Millisecond::from_dotnet_timespan(k), Ok(v), "{k}");
+        }
+    }
+    #[test]
+    fn should_convert_signed_dotnet_timespan() {
+        let cases = [
+            (0, "00:00:00"),
+            (-1, "-00:00:00.0000001"),
+            (-15_000_000, "-00:00:01.5000000"),
+            (-1_050_481_234_567, "-1.05:10:48.1234567"),
+            (1_050_481_234_567, "1.05:10:48.1234567"),
+            (i64::MIN, "-10675199.02:48:05.4775808"),
+        ];
+        for (k, v) in cases {
+            let ms = SignedMillisecond::from_dotnet_ticks(k);
+            assert_eq!(ms.to_dotnet_timespan(), v, "{k}");
+            assert_eq!(SignedMillisecond::from_dotnet_timespan(v), Ok(ms), "{v}");
+        }
+        let ms = SignedMillisecond::from_nanos_i128(-99);
+        assert_eq!(ms.to_dotnet_timespan(), "00:00:00");
+        assert_eq!(
+            SignedMillisecond::from_dotnet_timespan(" -00:60:00"),
+            Err(ParseError::new(ParseErrorKind::ComponentOutOfRange, 5))
+        );
+    }
+    #[test]
+    fn should_reject_invalid_dotnet_timespan() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("-00:00:01", ParseErrorKind::Negative, 0),
+            ("  -1.00:00", ParseErrorKind::Negative, 2),
+            ("24:00:00", ParseErrorKind::ComponentOutOfRange, 0),
+            ("1.24:00:00", ParseErrorKind::ComponentOutOfRange, 2),
+            ("00:00:60", ParseErrorKind::ComponentOutOfRange, 6),
+            ("00:00:01.12345678", ParseErrorKind::InvalidNumber, 9),
+            ("00:00:01.", ParseErrorKind::InvalidNumber, 9),
+            ("1.05", ParseErrorKind::UnexpectedToken, 4),
+            ("00:", ParseErrorKind::InvalidNumber, 3),
+            ("00:00:00:00", ParseErrorKind::UnexpectedToken, 8),
+            ("1d", ParseErrorKind::UnexpectedToken, 1),
+            (
+                "3938453320844195178974243141571391.23:59:59",
+                ParseErrorKind::Overflow,
+                0,
+            ),
+            ("6733061586903986339840.00:00", ParseErrorKind::Overflow, 0),
+        ];
+        for (k, kind, offset) in cases {
+            assert_eq!(
+                Millisecond::from_dotnet_timespan(k),
+                Err(ParseError::new(kind, offset)),
+                "{k}"
+            );
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use core::fmt::{Display, Formatter};
+
+/// The error type returned by the fallible conversions of a `Millisecond`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The value is too large to be represented by the target type.
+    Overflow,
+    /// A component is outside of its range, e.g. 60 minutes or 1000 millis.
+    ComponentOutOfRange,
+    /// The value is negative, but the target type is unsigned.
+    Negative,
+    /// The rate of a conversion, e.g. a sample rate, is zero.
+    ZeroRate,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Overflow => write!(f, "value is too large to be represented"),
+            Error::ComponentOutOfRange => write!(f, "component is out of its range"),
+            Error::Negative => write!(f, "value is negative"),
+            Error::ZeroRate => write!(f, "rate is zero"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// The error returned when parsing a duration string fails, along with the byte
+/// offset of the offending token in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+}
+
+/// The kind of a `ParseError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input has no duration at all.
+    Empty,
+    /// A number was expected, but the input has something else.
+    InvalidNumber,
+    /// A number is not followed by a unit.
+    MissingUnit,
+    /// The unit is not recognized.
+    UnknownUnit,
+    /// The unit is recognized, but cannot be converted exactly, e.g. months.
+    UnsupportedUnit,
+    /// The token is not expected at this position, e.g. a unit out of order.
+    UnexpectedToken,
+    /// The value is too large to be represented.
+    Overflow,
+    /// The value is negative, but the target type is unsigned.
+    Negative,
+    /// A component is outside of its range, e.g. 60 minutes in `00:60:00`.
+    ComponentOutOfRange,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ParseErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the offending token in the input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseErrorKind::Empty => write!(f, "empty duration"),
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ParseErrorKind::MissingUnit => write!(f, "missing unit"),
+            ParseErrorKind::UnknownUnit => write!(f, "unknown unit"),
+            ParseErrorKind::UnsupportedUnit => write!(f, "unsupported unit"),
+            ParseErrorKind::UnexpectedToken => write!(f, "unexpected token"),
+            ParseErrorKind::Overflow => write!(f, "value is too large to be represented"),
+            ParseErrorKind::Negative => write!(f, "value is negative"),
+            ParseErrorKind::ComponentOutOfRange => write!(f, "component is out of its range"),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)
+    }
+}
+
+impl core::error::Error for ParseError {}
@@ -3,9 +3,10 @@ use alloc::vec::Vec;
 use alloc::{format, vec};
 use core::fmt::{Display, Formatter};
 
-use crate::Millisecond;
+use crate::{FormatOptions, Language, Locale, Millisecond, SignedMillisecond, Unit};
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum MillisecondPart {
     Years(u64),
     Days(u16),
@@ -14,46 +15,177 @@ pub enum MillisecondPart {
     Seconds(u8),
     SecsAndMillis(u8, u16),
     Millis(u16),
+    MillisAndMicros(u16, u16),
     Micros(u16),
     Nanos(u16),
 }
 
 impl MillisecondPart {
     pub fn to_short_string(&self) -> String {
-        match self {
-            MillisecondPart::Years(x) => format!("{x}y"),
-            MillisecondPart::Days(x) => format!("{x}d"),
-            MillisecondPart::Hours(x) => format!("{x}h"),
-            MillisecondPart::Minutes(x) => format!("{x}m"),
-            MillisecondPart::Seconds(x) => format!("{x}s"),
-            MillisecondPart::Millis(x) => format!("{x}ms"),
-            MillisecondPart::SecsAndMillis(x, y) => format!("{x}.{y}s"),
-            MillisecondPart::Micros(x) => format!("{x}µs"),
-            MillisecondPart::Nanos(x) => format!("{x}ns"),
-        }
+        self.to_short_string_with_decimals(None)
     }
     pub fn to_long_string(&self) -> String {
-        match self {
-            MillisecondPart::Years(x) => with_pluralization(x, "year", 1),
-            MillisecondPart::Days(x) => with_pluralization(x, "day", 1),
-            MillisecondPart::Hours(x) => with_pluralization(x, "hour", 1),
-            MillisecondPart::Minutes(x) => with_pluralization(x, "minute", 1),
-            MillisecondPart::Seconds(x) => with_pluralization(x, "second", 1),
-            MillisecondPart::Millis(x) => with_pluralization(x, "millisecond", 1),
-            MillisecondPart::SecsAndMillis(x, y) => {
-                format!("{x}.{y} seconds")
-            }
-            MillisecondPart::Micros(x) => with_pluralization(x, "microsecond", 1),
-            MillisecondPart::Nanos(x) => with_pluralization(x, "nanosecond", 1),
-        }
+        self.to_long_string_with_decimals(None)
+    }
+    /// Formats the part in the short form, writing the fraction of `SecsAndMillis`
+    /// or `MillisAndMicros` with exactly `decimals` digits, or with trailing zeros
+    /// trimmed when `None`. Extra digits are truncated rather than rounded.
+    /// ### example
+    /// ```rust
+    /// use millisecond::MillisecondPart;
+    /// let part = MillisecondPart::SecsAndMillis(1, 50);
+    /// assert_eq!(part.to_short_string_with_decimals(None), "1.05s");
+    /// assert_eq!(part.to_short_string_with_decimals(Some(1)), "1.0s");
+    /// assert_eq!(part.to_short_string_with_decimals(Some(4)), "1.0500s");
+    /// ```
+    pub fn to_short_string_with_decimals(&self, decimals: Option<u8>) -> String {
+        self.to_string_in(decimals, false, &Language::English)
+    }
+    /// Formats the part in the long form, writing the fraction of `SecsAndMillis`
+    /// or `MillisAndMicros` with exactly `decimals` digits, or with trailing zeros
+    /// trimmed when `None`. Extra digits are truncated rather than rounded.
+    /// ### example
+    /// ```rust
+    /// use millisecond::MillisecondPart;
+    /// let part = MillisecondPart::SecsAndMillis(1, 5);
+    /// assert_eq!(part.to_long_string_with_decimals(None), "1.005 seconds");
+    /// assert_eq!(part.to_long_string_with_decimals(Some(0)), "1 second");
+    /// ```
+    pub fn to_long_string_with_decimals(&self, decimals: Option<u8>) -> String {
+        self.to_string_in(decimals, true, &Language::English)
+    }
+    /// Writes the part in the short or the `long` form of `locale`.
+    fn to_string_in<L: Locale + ?Sized>(
+        &self,
+        decimals: Option<u8>,
+        long: bool,
+        locale: &L,
+    ) -> String {
+        let (unit, int, thousandths) = match *self {
+            MillisecondPart::Years(x) => (Unit::Years, x, None),
+            MillisecondPart::Days(x) => (Unit::Days, x as u64, None),
+            MillisecondPart::Hours(x) => (Unit::Hours, x as u64, None),
+            MillisecondPart::Minutes(x) => (Unit::Minutes, x as u64, None),
+            MillisecondPart::Seconds(x) => (Unit::Seconds, x as u64, None),
+            MillisecondPart::SecsAndMillis(x, y) => (Unit::Seconds, x as u64, Some(y)),
+            MillisecondPart::Millis(x) => (Unit::Millis, x as u64, None),
+            MillisecondPart::MillisAndMicros(x, y) => (Unit::Millis, x as u64, Some(y)),
+            MillisecondPart::Micros(x) => (Unit::Micros, x as u64, None),
+            MillisecondPart::Nanos(x) => (Unit::Nanos, x as u64, None),
+        };
+        let number = match thousandths {
+            Some(y) => with_decimals(&int, y, decimals),
+            None => format!("{int}"),
+        };
+        let text = if long {
+            let category = locale.plural_category(int, number.contains('.'));
+            locale.unit_name(unit, category)
+        } else {
+            locale.unit_abbreviation(unit)
+        };
+        format!(
+            "{}{}{text}",
+            localize_number(&number, locale),
+            locale.unit_separator(long)
+        )
     }
     pub fn from_millisecond(ms: &Millisecond) -> Vec<MillisecondPart> {
         Self::from_millisecond_with_option(ms, true)
     }
+    /// Splits the magnitude of a signed value into its parts;
+    /// the sign itself is available from `SignedMillisecond::is_negative`.
+    pub fn from_signed_millisecond(ms: &SignedMillisecond) -> Vec<MillisecondPart> {
+        Self::from_millisecond(&ms.magnitude())
+    }
+    /// Creates a part of the given unit with a value of zero.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{MillisecondPart, Unit};
+    /// assert_eq!(MillisecondPart::zero(Unit::Seconds).to_short_string(), "0s");
+    /// ```
+    pub fn zero(unit: Unit) -> MillisecondPart {
+        match unit {
+            Unit::Years => MillisecondPart::Years(0),
+            Unit::Days => MillisecondPart::Days(0),
+            Unit::Hours => MillisecondPart::Hours(0),
+            Unit::Minutes => MillisecondPart::Minutes(0),
+            Unit::Seconds => MillisecondPart::Seconds(0),
+            Unit::Millis => MillisecondPart::Millis(0),
+            Unit::Micros => MillisecondPart::Micros(0),
+            Unit::Nanos => MillisecondPart::Nanos(0),
+        }
+    }
     pub fn from_millisecond_with_option(
         ms: &Millisecond,
         merge_secs_and_millis: bool,
     ) -> Vec<MillisecondPart> {
+        Self::from_millisecond_with_zero_unit(ms, merge_secs_and_millis, Unit::Millis)
+    }
+    /// Splits the value into its non-zero parts. A zero-length value results
+    /// in a single zero part of `zero_unit`, so it never renders as an empty string.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, MillisecondPart, Unit};
+    /// let parts = MillisecondPart::from_millisecond_with_zero_unit(&Millisecond::ZERO, true, Unit::Seconds);
+    /// assert_eq!(parts.len(), 1);
+    /// assert_eq!(parts[0].to_long_string(), "0 seconds");
+    /// ```
+    pub fn from_millisecond_with_zero_unit(
+        ms: &Millisecond,
+        merge_secs_and_millis: bool,
+        zero_unit: Unit,
+    ) -> Vec<MillisecondPart> {
+        let options = FormatOptions::new()
+            .separate_millis(!merge_secs_and_millis)
+            .zero_unit(zero_unit);
+        Self::from_millisecond_with_options(ms, &options)
+    }
+    /// Splits the value into the parts to be shown according to `options`, taking
+    /// into account how milliseconds and sub-milliseconds are grouped, and the unit count.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{FormatOptions, Millisecond, MillisecondPart};
+    /// let ms = Millisecond::from_micros(61_001_500);
+    /// let opts = FormatOptions::new().format_sub_millis(false).separate_millis(true);
+    /// let parts = MillisecondPart::from_millisecond_with_options(&ms, &opts);
+    /// assert_eq!(parts.len(), 3);
+    /// assert_eq!(parts[2].to_short_string(), "1.5ms");
+    /// ```
+    pub fn from_millisecond_with_options(
+        ms: &Millisecond,
+        options: &FormatOptions,
+    ) -> Vec<MillisecondPart> {
+        let ms = ms.checked_normalize().unwrap_or(Millisecond::MAX);
+        let mut v = Self::split(&ms, options);
+
+        let count = if options.compact {
+            Some(1)
+        } else {
+            options.unit_count.map(|x| x.max(1))
+        };
+        if let Some(count) = count.filter(|x| v.len() > *x || options.compact) {
+            // round the dropped parts into the last kept one, which may carry upward; in
+            // compact mode its fraction is dropped too, e.g. 59.9s becomes 1m
+            let last = &v[count - 1];
+            let unit = if options.compact {
+                last.whole_unit()
+            } else {
+                last.smallest_unit()
+            };
+            let unit = unit.nanos();
+            let nanos = ms.as_nanos();
+            let floor = nanos - nanos % unit;
+            let rounded = if nanos % unit * 2 >= unit {
+                Millisecond::try_from_nanos(floor + unit)
+            } else {
+                Millisecond::try_from_nanos(floor)
+            };
+            v = Self::split(&rounded.unwrap_or(Millisecond::from_nanos(floor)), options);
+            v.truncate(count);
+        }
+        v
+    }
+    fn split(ms: &Millisecond, options: &FormatOptions) -> Vec<MillisecondPart> {
         let mut v = vec![];
         if ms.years > 0 {
             v.push(MillisecondPart::Years(ms.years));
@@ -68,6 +200,7 @@ impl MillisecondPart {
             v.push(MillisecondPart::Minutes(ms.minutes));
         }
 
+        let merge_secs_and_millis = !options.separate_millis && ms.seconds > 0;
         if ms.seconds > 0 {
             if merge_secs_and_millis && ms.millis > 0 {
                 v.push(MillisecondPart::SecsAndMillis(ms.seconds, ms.millis));
@@ -75,19 +208,92 @@ impl MillisecondPart {
                 v.push(MillisecondPart::Seconds(ms.seconds));
             }
         }
-        if !merge_secs_and_millis && ms.millis > 0 {
-            v.push(MillisecondPart::Millis(ms.millis));
-        }
 
-        if ms.micros > 0 {
-            v.push(MillisecondPart::Micros(ms.micros));
-        }
-        if ms.nanos > 0 {
-            v.push(MillisecondPart::Nanos(ms.nanos));
+        if options.format_sub_millis {
+            if !merge_secs_and_millis && ms.millis > 0 {
+                v.push(MillisecondPart::Millis(ms.millis));
+            }
+            if ms.micros > 0 {
+                v.push(MillisecondPart::Micros(ms.micros));
+            }
+            if ms.nanos > 0 {
+                v.push(MillisecondPart::Nanos(ms.nanos));
+            }
+        } else if !merge_secs_and_millis && (ms.millis > 0 || (v.is_empty() && ms.micros > 0)) {
+            if ms.micros > 0 {
+                v.push(MillisecondPart::MillisAndMicros(ms.millis, ms.micros));
+            } else {
+                v.push(MillisecondPart::Millis(ms.millis));
+            }
         }
 
+        if v.is_empty() {
+            v.push(MillisecondPart::zero(options.zero_unit));
+        }
         v
     }
+    /// Returns the unit of the whole number the part holds, e.g. `Unit::Seconds` for
+    /// `SecsAndMillis`.
+    fn whole_unit(&self) -> Unit {
+        match self {
+            MillisecondPart::SecsAndMillis(..) => Unit::Seconds,
+            MillisecondPart::MillisAndMicros(..) => Unit::Millis,
+            part => part.smallest_unit(),
+        }
+    }
+    /// Returns the smallest unit the part holds, e.g. `Unit::Millis` for `SecsAndMillis`.
+    fn smallest_unit(&self) -> Unit {
+        match self {
+            MillisecondPart::Years(_) => Unit::Years,
+            MillisecondPart::Days(_) => Unit::Days,
+            MillisecondPart::Hours(_) => Unit::Hours,
+            MillisecondPart::Minutes(_) => Unit::Minutes,
+            MillisecondPart::Seconds(_) => Unit::Seconds,
+            MillisecondPart::SecsAndMillis(..) | MillisecondPart::Millis(_) => Unit::Millis,
+            MillisecondPart::MillisAndMicros(..) | MillisecondPart::Micros(_) => Unit::Micros,
+            MillisecondPart::Nanos(_) => Unit::Nanos,
+        }
+    }
+    /// Formats the part according to `options`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{FormatOptions, MillisecondPart};
+    /// let opts = FormatOptions::new().verbose(true).seconds_decimal_digits(1);
+    /// assert_eq!(MillisecondPart::SecsAndMillis(1, 250).format_with(&opts), "1.2 seconds");
+    /// ```
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.format_with_in(options, &Language::English)
+    }
+    /// Formats the part according to `options` in the words of `locale`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{FormatOptions, Language, MillisecondPart};
+    /// let opts = FormatOptions::new().verbose(true);
+    /// let part = MillisecondPart::SecsAndMillis(1, 500);
+    /// assert_eq!(part.format_with_in(&opts, &Language::French), "1,5 seconde");
+    /// ```
+    pub fn format_with_in<L: Locale + ?Sized>(
+        &self,
+        options: &FormatOptions,
+        locale: &L,
+    ) -> String {
+        let decimals = if options.compact {
+            Some(0)
+        } else if let MillisecondPart::MillisAndMicros(..) = self {
+            options.millis_decimal_digits
+        } else {
+            options.seconds_decimal_digits
+        };
+        let part = match self {
+            MillisecondPart::Seconds(x)
+                if options.keep_decimals_on_whole_seconds && !options.separate_millis =>
+            {
+                &MillisecondPart::SecsAndMillis(*x, 0)
+            }
+            part => part,
+        };
+        part.to_string_in(decimals, options.verbose, locale)
+    }
 }
 
 impl Display for MillisecondPart {
@@ -98,10 +304,249 @@ impl Display for MillisecondPart {
 
 unsafe impl Sync for MillisecondPart {}
 
-fn with_pluralization<T: Eq + Display>(val: &T, text: &str, single_val: T) -> String {
-    if *val == single_val {
-        format!("{val} {text}")
+/// Writes the ASCII digits and the `.` of `number` in the ones of `locale`.
+fn localize_number<L: Locale + ?Sized>(number: &str, locale: &L) -> String {
+    number
+        .chars()
+        .map(|c| match c.to_digit(10) {
+            Some(d) => String::from(locale.digit(d as u8)),
+            None => String::from(locale.decimal_separator()),
+        })
+        .collect()
+}
+
+/// Writes `val` followed by `thousandths` as a decimal fraction, e.g. `1` and `5` as `1.005`.
+fn with_decimals<T: Display>(val: &T, thousandths: u16, decimals: Option<u8>) -> String {
+    with_nanos_fraction(val, thousandths as u32 * 1_000_000, decimals)
+}
+
+/// Writes `val` followed by `nanos` as a decimal fraction of nine digits, either with
+/// trailing zeros trimmed, or truncated/padded to exactly `decimals` digits.
+pub(crate) fn with_nanos_fraction<T: Display>(val: &T, nanos: u32, decimals: Option<u8>) -> String {
+    let digits = format!("{nanos:09}");
+    let fraction = match decimals {
+        None => String::from(digits.trim_end_matches('0')),
+        Some(n) if (n as usize) <= digits.len() => String::from(&digits[..n as usize]),
+        Some(n) => format!("{digits:0<width$}", width = n as usize),
+    };
+    if fraction.is_empty() {
+        format!("{val}")
     } else {
-        format!("{val} {text}s")
+        format!("{val}.{fraction}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_pad_secs_and_millis() {
+        let cases = [
+            ((1, 5), "1.005s", "1.005 seconds"),
+            ((1, 50), "1.05s", "1.05 seconds"),
+            ((1, 500), "1.5s", "1.5 seconds"),
+            ((59, 999), "59.999s", "59.999 seconds"),
+            ((2, 0), "2s", "2 seconds"),
+        ];
+        for ((x, y), short, long) in cases {
+            let part = MillisecondPart::SecsAndMillis(x, y);
+            assert_eq!(part.to_short_string(), short);
+            assert_eq!(part.to_long_string(), long);
+        }
+    }
+    #[test]
+    fn should_format_fixed_decimals() {
+        let part = MillisecondPart::SecsAndMillis(1, 59);
+        let cases = [
+            (0, "1s", "1 second"),
+            (1, "1.0s", "1.0 seconds"),
+            (2, "1.05s", "1.05 seconds"),
+            (3, "1.059s", "1.059 seconds"),
+            (5, "1.05900s", "1.05900 seconds"),
+        ];
+        for (decimals, short, long) in cases {
+            assert_eq!(part.to_short_string_with_decimals(Some(decimals)), short);
+            assert_eq!(part.to_long_string_with_decimals(Some(decimals)), long);
+        }
+        assert_eq!(
+            MillisecondPart::Seconds(1).to_short_string_with_decimals(Some(2)),
+            "1s"
+        );
+    }
+    #[test]
+    fn should_format_zero() {
+        let ms = Millisecond::ZERO;
+        assert_eq!(ms.to_short_string(), "0ms");
+        assert_eq!(ms.to_long_string(), "0 milliseconds");
+        assert_eq!(ms.to_short_string_with_zero_unit(Unit::Seconds), "0s");
+        assert_eq!(ms.to_long_string_with_zero_unit(Unit::Seconds), "0 seconds");
+        assert_eq!(ms.to_long_string_with_zero_unit(Unit::Years), "0 years");
+        assert_eq!(
+            Millisecond::from_secs(1).to_short_string_with_zero_unit(Unit::Nanos),
+            "1s"
+        );
+        assert_eq!(SignedMillisecond::default().to_short_string(), "0ms");
+    }
+    #[test]
+    fn should_format_with_default_options() {
+        let cases = [
+            0,
+            1,
+            999,
+            1_000,
+            1_005,
+            61_050,
+            33023448000,
+            9007199254740991,
+        ];
+        let opts = FormatOptions::default();
+        for k in cases {
+            let ms = Millisecond::from_nanos(k * 1_001_001);
+            assert_eq!(ms.format_with(&opts), ms.to_short_string());
+            assert_eq!(
+                ms.format_with(&opts.clone().verbose(true)),
+                ms.to_long_string()
+            );
+        }
+    }
+    #[test]
+    fn should_format_with_options() {
+        let ms = Millisecond::from_nanos(3_723_004_005_006);
+        let cases = [
+            (FormatOptions::new(), "1h 2m 3.004s 5µs 6ns"),
+            (FormatOptions::new().compact(true), "1h"),
+            (FormatOptions::new().unit_count(3), "1h 2m 3.004s"),
+            (FormatOptions::new().unit_count(4), "1h 2m 3.004s 5µs"),
+            (FormatOptions::new().unit_count(0), "1h"),
+            (
+                FormatOptions::new().separate_millis(true),
+                "1h 2m 3s 4ms 5µs 6ns",
+            ),
+            (
+                FormatOptions::new().format_sub_millis(false),
+                "1h 2m 3.004s",
+            ),
+            (
+                FormatOptions::new()
+                    .format_sub_millis(false)
+                    .separate_millis(true),
+                "1h 2m 3s 4.005ms",
+            ),
+            (
+                FormatOptions::new()
+                    .format_sub_millis(false)
+                    .separate_millis(true)
+                    .millis_decimal_digits(1),
+                "1h 2m 3s 4.0ms",
+            ),
+            (
+                FormatOptions::new().seconds_decimal_digits(1),
+                "1h 2m 3.0s 5µs 6ns",
+            ),
+            (
+                FormatOptions::new().verbose(true).unit_count(3),
+                "1 hour 2 minutes 3.004 seconds",
+            ),
+        ];
+        for (opts, expected) in cases {
+            assert_eq!(ms.format_with(&opts), expected, "{opts:?}");
+        }
+    }
+    #[test]
+    fn should_round_dropped_units() {
+        let cases = [
+            (Millisecond::from_millis(3_599_900), 1, "1h", "1 hour"),
+            (
+                Millisecond::from_millis(3_599_900),
+                2,
+                "59m 59.9s",
+                "59 minutes 59.9 seconds",
+            ),
+            (
+                Millisecond::from_millis(3_599_900),
+                3,
+                "59m 59.9s",
+                "59 minutes 59.9 seconds",
+            ),
+            (Millisecond::from_millis(3_570_000), 1, "1h", "1 hour"),
+            (Millisecond::from_millis(3_569_999), 1, "59m", "59 minutes"),
+            (
+                Millisecond::from_millis(90_061_000),
+                2,
+                "1d 1h",
+                "1 day 1 hour",
+            ),
+            (Millisecond::from_millis(172_799_000), 2, "2d", "2 days"),
+            (Millisecond::from_millis(31_535_999_000), 1, "1y", "1 year"),
+            (Millisecond::from_micros(1_500), 1, "2ms", "2 milliseconds"),
+            (Millisecond::from_micros(1_499), 1, "1ms", "1 millisecond"),
+            (
+                Millisecond::from_nanos(2_001_500),
+                2,
+                "2ms 2µs",
+                "2 milliseconds 2 microseconds",
+            ),
+        ];
+        for (ms, count, short, long) in cases {
+            assert_eq!(ms.to_short_string_with_units(count), short, "{ms:?}");
+            assert_eq!(ms.to_long_string_with_units(count), long, "{ms:?}");
+        }
+
+        let ms = Millisecond::from_nanos(3_723_004_005_006);
+        assert_eq!(ms.to_short_string_with_units(10), ms.to_short_string());
+        assert_eq!(
+            Millisecond::MAX.to_short_string_with_units(1),
+            "18446744073709551615y"
+        );
+        assert_eq!(Millisecond::ZERO.to_short_string_with_units(1), "0ms");
+    }
+    #[test]
+    fn should_round_fraction_in_compact_mode() {
+        let opts = FormatOptions::new().compact(true);
+        let sub_millis = opts.clone().format_sub_millis(false);
+        let cases = [
+            (Millisecond::from_millis(59_900), &opts, "1m"),
+            (Millisecond::from_millis(119_900), &opts, "2m"),
+            (Millisecond::from_millis(59_400), &opts, "59s"),
+            (Millisecond::from_micros(999_900), &opts, "1s"),
+            (Millisecond::from_micros(999_900), &sub_millis, "1s"),
+            (Millisecond::from_micros(999_400), &sub_millis, "999ms"),
+            (Millisecond::from_micros(600), &sub_millis, "1ms"),
+        ];
+        for (ms, opts, expected) in cases {
+            assert_eq!(ms.format_with(opts), expected, "{ms:?}");
+        }
+        assert_eq!(Millisecond::MAX.format_with(&opts), "18446744073709551615y");
+    }
+    #[test]
+    fn should_keep_decimals_on_whole_seconds() {
+        let ms = Millisecond::from_secs(13);
+        let opts = FormatOptions::new().seconds_decimal_digits(1);
+        assert_eq!(ms.format_with(&opts), "13s");
+        let opts = opts.keep_decimals_on_whole_seconds(true);
+        assert_eq!(ms.format_with(&opts), "13.0s");
+        assert_eq!(ms.format_with(&opts.verbose(true)), "13.0 seconds");
+    }
+    #[test]
+    fn should_format_sub_millis_as_fraction() {
+        let opts = FormatOptions::new().format_sub_millis(false);
+        assert_eq!(Millisecond::from_micros(1_500).format_with(&opts), "1.5ms");
+        assert_eq!(Millisecond::from_micros(500).format_with(&opts), "0.5ms");
+        assert_eq!(Millisecond::from_nanos(500).format_with(&opts), "0ms");
+        assert_eq!(
+            Millisecond::from_micros(1_000).format_with(&opts.verbose(true)),
+            "1 millisecond"
+        );
+    }
+    #[test]
+    fn should_format_millis_without_seconds() {
+        let ms = Millisecond::from_millis(60_005);
+        assert_eq!(ms.to_short_string(), "1m 5ms");
+        assert_eq!(ms.to_long_string(), "1 minute 5 milliseconds");
+
+        let ms = Millisecond::from_millis(61_005);
+        assert_eq!(ms.to_short_string(), "1m 1.005s");
+        assert_eq!(ms.to_long_string(), "1 minute 1.005 seconds");
     }
 }
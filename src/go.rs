@@ -0,0 +1,234 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::formatter::with_nanos_fraction;
+use crate::parser::{add_nanos, to_unsigned, Scanner};
+use crate::{Millisecond, SignedMillisecond, Unit};
+
+impl Millisecond {
+    /// Formats the instance the same as `time.Duration.String()` in Go, e.g. `1h2m3.5s`.
+    /// Hours are the largest unit, and values below a second use the smallest fitting
+    /// unit among `ms`, `µs` and `ns`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::from_millis(3_723_500).to_go_duration(), "1h2m3.5s");
+    /// assert_eq!(Millisecond::from_micros(1_500).to_go_duration(), "1.5ms");
+    /// assert_eq!(Millisecond::from_hours(1).to_go_duration(), "1h0m0s");
+    /// assert_eq!(Millisecond::ZERO.to_go_duration(), "0s");
+    /// ```
+    pub fn to_go_duration(&self) -> String {
+        let nanos = self.as_nanos();
+        if nanos < Unit::Seconds.nanos() {
+            let nanos = nanos as u32;
+            return match nanos {
+                0 => String::from("0s"),
+                1..1_000 => format!("{nanos}ns"),
+                1_000..1_000_000 => {
+                    let fraction = nanos % 1_000 * 1_000_000;
+                    format!(
+                        "{}µs",
+                        with_nanos_fraction(&(nanos / 1_000), fraction, None)
+                    )
+                }
+                _ => {
+                    let fraction = nanos % 1_000_000 * 1_000;
+                    format!(
+                        "{}ms",
+                        with_nanos_fraction(&(nanos / 1_000_000), fraction, None)
+                    )
+                }
+            };
+        }
+
+        let seconds = nanos / Unit::Seconds.nanos();
+        let fraction = (nanos % Unit::Seconds.nanos()) as u32;
+        let seconds = with_nanos_fraction(&(seconds % 60), fraction, None);
+        let minutes = nanos / Unit::Minutes.nanos();
+        let hours = nanos / Unit::Hours.nanos();
+        if hours > 0 {
+            format!("{hours}h{}m{seconds}s", minutes % 60)
+        } else if minutes > 0 {
+            format!("{minutes}m{seconds}s")
+        } else {
+            format!("{seconds}s")
+        }
+    }
+
+    /// Parses a duration the same as `time.ParseDuration` in Go, e.g. `1h2m3.5s`, `1.5ms`
+    /// or `250ns`. Negative values are rejected with `ParseErrorKind::Negative`, see
+    /// `SignedMillisecond::from_go_duration` for them.
+    ///
+    /// Unlike Go, values beyond the range of an `int64` of nanoseconds are accepted.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_go_duration("1h2m3.5s").unwrap();
+    /// assert_eq!(ms, Millisecond::from_millis(3_723_500));
+    /// ```
+    pub fn from_go_duration(input: &str) -> Result<Self, ParseError> {
+        parse_go_duration(input).and_then(to_unsigned)
+    }
+}
+
+impl SignedMillisecond {
+    /// Formats the instance the same as `time.Duration.String()` in Go, e.g. `-1h2m3.5s`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::SignedMillisecond;
+    /// let ms = SignedMillisecond::from_millis_i128(-1_500);
+    /// assert_eq!(ms.to_go_duration(), "-1.5s");
+    /// ```
+    pub fn to_go_duration(&self) -> String {
+        let text = self.magnitude().to_go_duration();
+        if self.is_negative() {
+            format!("-{text}")
+        } else {
+            text
+        }
+    }
+
+    /// Parses a duration the same as `time.ParseDuration` in Go, including its optional sign.
+    /// ### example
+    /// ```rust
+    /// use millisecond::SignedMillisecond;
+    /// let ms = SignedMillisecond::from_go_duration("-1.5h").unwrap();
+    /// assert_eq!(ms, SignedMillisecond::from_millis_i128(-5_400_000));
+    /// ```
+    pub fn from_go_duration(input: &str) -> Result<Self, ParseError> {
+        parse_go_duration(input).map(|(ms, _)| ms)
+    }
+}
+
+/// Parses a Go duration, along with the offset of its sign.
+fn parse_go_duration(input: &str) -> Result<(SignedMillisecond, usize), ParseError> {
+    let mut s = Scanner::new(input);
+    let sign = s.pos();
+    let negative = s.eat("-");
+    if !negative {
+        s.eat("+");
+    }
+    match &input[s.pos()..] {
+        "" => return Err(ParseError::new(ParseErrorKind::Empty, s.pos())),
+        "0" => return Ok((SignedMillisecond::default(), sign)),
+        _ => {}
+    }
+
+    let mut total: u128 = 0;
+    while !s.is_end() {
+        let start = s.pos();
+        let number = s.loose_decimal()?;
+
+        let unit_start = s.pos();
+        let nanos = match s.take_while(|c| c != '.' && !c.is_ascii_digit()) {
+            "" => return Err(ParseError::new(ParseErrorKind::MissingUnit, unit_start)),
+            "ns" => Unit::Nanos.nanos(),
+            "us" | "µs" | "μs" => Unit::Micros.nanos(),
+            "ms" => Unit::Millis.nanos(),
+            "s" => Unit::Seconds.nanos(),
+            "m" => Unit::Minutes.nanos(),
+            "h" => Unit::Hours.nanos(),
+            _ => return Err(ParseError::new(ParseErrorKind::UnknownUnit, unit_start)),
+        };
+        total = add_nanos(total, number.scale(nanos), start)?;
+    }
+    Ok((
+        SignedMillisecond::new(negative, Millisecond::from_nanos(total)),
+        sign,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_go_duration() {
+        let cases = [
+            (0, "0s"),
+            (1, "1ns"),
+            (1_100, "1.1µs"),
+            (2_200_000, "2.2ms"),
+            (3_300_000_000, "3.3s"),
+            (4 * 60_000_000_000 + 5_000_000_000, "4m5s"),
+            (4 * 60_000_000_000 + 5_001_000_000, "4m5.001s"),
+            (
+                5 * 3_600_000_000_000 + 6 * 60_000_000_000 + 7_001_000_000,
+                "5h6m7.001s",
+            ),
+            (8 * 60_000_000_000 + 1, "8m0.000000001s"),
+            (999_999_999, "999.999999ms"),
+            (60_000_000_000, "1m0s"),
+            (3_600_000_000_000, "1h0m0s"),
+            (86_400_000_000_000, "24h0m0s"),
+            (i64::MAX as u128, "2562047h47m16.854775807s"),
+        ];
+        for (k, expected) in cases {
+            let ms = Millisecond::from_nanos(k);
+            assert_eq!(ms.to_go_duration(), expected);
+            assert_eq!(
+                Millisecond::from_go_duration(expected),
+                Ok(ms),
+                "{expected}"
+            );
+        }
+        let ms = SignedMillisecond::from_nanos_i128(i64::MIN as i128);
+        assert_eq!(ms.to_go_duration(), "-2562047h47m16.854775808s");
+    }
+    #[test]
+    fn should_parse_go_duration() {
+        let cases = [
+            ("0", 0),
+            ("+0", 0),
+            ("-0", 0),
+            ("5s", 5_000_000_000),
+            ("+5s", 5_000_000_000),
+            ("1.5h", 5_400_000_000_000),
+            (".5s", 500_000_000),
+            ("5.s", 5_000_000_000),
+            ("1.004s", 1_004_000_000),
+            ("100.00100s", 100_001_000_000),
+            ("1h1m1s", 3_661_000_000_000),
+            ("3us", 3_000),
+            ("3µs", 3_000),
+            ("3μs", 3_000),
+            ("1s1s", 2_000_000_000),
+            ("0.100000000000000000000h", 360_000_000_000),
+        ];
+        for (k, v) in cases {
+            assert_eq!(
+                Millisecond::from_go_duration(k),
+                Ok(Millisecond::from_nanos(v)),
+                "{k}"
+            );
+        }
+        assert_eq!(
+            SignedMillisecond::from_go_duration("-1h2m"),
+            Ok(SignedMillisecond::from_millis_i128(-3_720_000))
+        );
+    }
+    #[test]
+    fn should_reject_invalid_go_duration() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("-", ParseErrorKind::Empty, 1),
+            ("3", ParseErrorKind::MissingUnit, 1),
+            ("s", ParseErrorKind::InvalidNumber, 0),
+            (".", ParseErrorKind::InvalidNumber, 0),
+            ("-.s", ParseErrorKind::InvalidNumber, 1),
+            ("1d", ParseErrorKind::UnknownUnit, 1),
+            ("1h 2m", ParseErrorKind::UnknownUnit, 1),
+            ("1H", ParseErrorKind::UnknownUnit, 1),
+            ("-1s", ParseErrorKind::Negative, 0),
+            ("1h9999999999999999999999999h", ParseErrorKind::Overflow, 2),
+        ];
+        for (k, kind, offset) in cases {
+            assert_eq!(
+                Millisecond::from_go_duration(k),
+                Err(ParseError::new(kind, offset)),
+                "{k}"
+            );
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parser::{add_nanos, Scanner};
+use crate::{Millisecond, Unit};
+
+/// A year in `humantime` is 365.25 days, and a month is 30.44 days.
+const SECS_PER_YEAR: u128 = 31_557_600;
+const SECS_PER_MONTH: u128 = 2_630_016;
+
+impl Millisecond {
+    /// Formats the instance the same as `humantime::format_duration`, e.g.
+    /// `1year 17days 5h 10m 48s` or `15days 2min 2s`. The same as `humantime`, a year
+    /// is 365.25 days and a month is 30.44 days, so whole `Millisecond::years` are
+    /// not written as `year`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_secs(1_296_122);
+    /// assert_eq!(ms.to_humantime(), "15days 2m 2s");
+    /// assert_eq!(Millisecond::from_micros(1_500).to_humantime(), "1ms 500us");
+    /// assert_eq!(Millisecond::ZERO.to_humantime(), "0s");
+    /// ```
+    pub fn to_humantime(&self) -> String {
+        let nanos = self.as_nanos();
+        if nanos == 0 {
+            return String::from("0s");
+        }
+        let secs = nanos / Unit::Seconds.nanos();
+        let subsec = nanos % Unit::Seconds.nanos();
+        let year_secs = secs % SECS_PER_YEAR;
+        let month_secs = year_secs % SECS_PER_MONTH;
+        let day_secs = month_secs % 86_400;
+
+        let plural = [
+            (secs / SECS_PER_YEAR, "year"),
+            (year_secs / SECS_PER_MONTH, "month"),
+            (month_secs / 86_400, "day"),
+        ];
+        let singular = [
+            (day_secs / 3_600, "h"),
+            (day_secs % 3_600 / 60, "m"),
+            (day_secs % 60, "s"),
+            (subsec / 1_000_000, "ms"),
+            (subsec / 1_000 % 1_000, "us"),
+            (subsec % 1_000, "ns"),
+        ];
+        let mut items = Vec::new();
+        for (value, name) in plural.into_iter().filter(|(x, _)| *x > 0) {
+            let suffix = if value > 1 { "s" } else { "" };
+            items.push(format!("{value}{name}{suffix}"));
+        }
+        for (value, name) in singular.into_iter().filter(|(x, _)| *x > 0) {
+            items.push(format!("{value}{name}"));
+        }
+        items.join(" ")
+    }
+
+    /// Parses a duration the same as `humantime::parse_duration`, e.g. `1year 17days 5h`
+    /// or `15days 2min 2s`, with a year of 365.25 days and a month (`M`) of 30.44 days.
+    /// The same as `humantime`, numbers are integers, units are ASCII letters, and
+    /// whitespace is allowed anywhere but inside a unit, even between digits.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_humantime("15days 2min 2s").unwrap();
+    /// assert_eq!(ms, Millisecond::from_secs(1_296_122));
+    ///
+    /// let ms = Millisecond::from_humantime("2h37min").unwrap();
+    /// assert_eq!(ms, Millisecond::from_minutes(157));
+    /// ```
+    pub fn from_humantime(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        s.skip_whitespace();
+        if s.is_end() {
+            return Err(ParseError::new(ParseErrorKind::Empty, s.pos()));
+        }
+
+        let mut total: u128 = 0;
+        while !s.is_end() {
+            let start = s.pos();
+            if !s.peek().is_some_and(|c| c.is_ascii_digit()) {
+                return Err(ParseError::new(ParseErrorKind::InvalidNumber, start));
+            }
+            let value = s
+                .take_while(|c| c.is_ascii_digit() || c.is_whitespace())
+                .chars()
+                .filter_map(|c| c.to_digit(10))
+                .try_fold(0u64, |n, d| n.checked_mul(10)?.checked_add(d as u64))
+                .ok_or(ParseError::new(ParseErrorKind::Overflow, start))?;
+
+            let unit_start = s.pos();
+            let nanos = match s.take_while(|c| c.is_ascii_alphabetic()) {
+                "" if s.is_end() => {
+                    return Err(ParseError::new(ParseErrorKind::MissingUnit, unit_start))
+                }
+                "" => return Err(ParseError::new(ParseErrorKind::UnexpectedToken, unit_start)),
+                "nanos" | "nsec" | "ns" => Unit::Nanos.nanos(),
+                "usec" | "us" => Unit::Micros.nanos(),
+                "millis" | "msec" | "ms" => Unit::Millis.nanos(),
+                "seconds" | "second" | "secs" | "sec" | "s" => Unit::Seconds.nanos(),
+                "minutes" | "minute" | "min" | "mins" | "m" => Unit::Minutes.nanos(),
+                "hours" | "hour" | "hr" | "hrs" | "h" => Unit::Hours.nanos(),
+                "days" | "day" | "d" => Unit::Days.nanos(),
+                "weeks" | "week" | "w" => 7 * Unit::Days.nanos(),
+                "months" | "month" | "M" => SECS_PER_MONTH * Unit::Seconds.nanos(),
+                "years" | "year" | "y" => SECS_PER_YEAR * Unit::Seconds.nanos(),
+                _ => return Err(ParseError::new(ParseErrorKind::UnknownUnit, unit_start)),
+            };
+            match s.peek() {
+                Some(c) if !c.is_ascii_digit() && !c.is_whitespace() => {
+                    return Err(ParseError::new(ParseErrorKind::UnexpectedToken, s.pos()))
+                }
+                _ => s.skip_whitespace(),
+            }
+            total = add_nanos(total, (value as u128).checked_mul(nanos), start)?;
+        }
+        Ok(Millisecond::from_nanos(total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_humantime() {
+        let cases = [
+            (Millisecond::ZERO, "0s"),
+            (Millisecond::from_nanos(1), "1ns"),
+            (Millisecond::from_nanos(1_001_001), "1ms 1us 1ns"),
+            (Millisecond::from_secs(3_600), "1h"),
+            (Millisecond::from_secs(1_296_122), "15days 2m 2s"),
+            (Millisecond::from_days(1), "1day"),
+            (Millisecond::from_secs(2_630_016), "1month"),
+            (Millisecond::from_secs(2 * 2_630_016), "2months"),
+            (Millisecond::from_secs(31_557_600), "1year"),
+            (Millisecond::from_years(1), "11months 30days 3h 50m 24s"),
+            (
+                Millisecond::from_secs(2 * 31_557_600 + 2_630_016 + 90_061),
+                "2years 1month 1day 1h 1m 1s",
+            ),
+            (
+                Millisecond::from_nanos(150_000_000_000_000_000),
+                "4years 9months 1day 3h 37m 36s",
+            ),
+        ];
+        for (ms, v) in cases {
+            assert_eq!(ms.to_humantime(), v);
+            assert_eq!(Millisecond::from_humantime(v), Ok(ms), "{v}");
+        }
+    }
+    #[test]
+    fn should_parse_humantime() {
+        let cases = [
+            ("17nsec", Millisecond::from_nanos(17)),
+            ("33ns", Millisecond::from_nanos(33)),
+            ("3usec 78us", Millisecond::from_micros(81)),
+            ("31msec 6millis", Millisecond::from_millis(37)),
+            ("2h37min", Millisecond::from_minutes(157)),
+            ("17 min 1 secs", Millisecond::from_secs(1_021)),
+            ("2 weeks", Millisecond::from_days(14)),
+            ("1 2s", Millisecond::from_secs(12)),
+            ("  5s  ", Millisecond::from_secs(5)),
+            ("1year", Millisecond::from_secs(31_557_600)),
+            ("1M", Millisecond::from_secs(2_630_016)),
+        ];
+        for (k, v) in cases {
+            assert_eq!(Millisecond::from_humantime(k), Ok(v), "{k}");
+        }
+    }
+    #[test]
+    fn should_reject_invalid_humantime() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("  ", ParseErrorKind::Empty, 2),
+            ("10", ParseErrorKind::MissingUnit, 2),
+            ("1.5s", ParseErrorKind::UnexpectedToken, 1),
+            ("1s,", ParseErrorKind::UnexpectedToken, 2),
+            ("s", ParseErrorKind::InvalidNumber, 0),
+            ("1 m 2 x", ParseErrorKind::UnknownUnit, 6),
+            ("1µs", ParseErrorKind::UnexpectedToken, 1),
+            ("1m1mo", ParseErrorKind::UnknownUnit, 3),
+            ("99999999999999999999s", ParseErrorKind::Overflow, 0),
+        ];
+        for (k, kind, offset) in cases {
+            assert_eq!(
+                Millisecond::from_humantime(k),
+                Err(ParseError::new(kind, offset)),
+                "{k}"
+            );
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::formatter::with_nanos_fraction;
+use crate::parser::{add_nanos, Scanner};
+use crate::{Millisecond, Unit};
+
+impl Millisecond {
+    /// Formats the instance as an ISO 8601 duration, e.g. `P1Y17DT5H10M48.5S`,
+    /// which is also the format of `xs:duration` and Java's `Duration`.
+    /// A zero-length value is formatted as `PT0S`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_millis(33023448500);
+    /// assert_eq!(ms.to_iso8601(), "P1Y17DT5H10M48.5S");
+    /// assert_eq!(Millisecond::ZERO.to_iso8601(), "PT0S");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        let ms = self.checked_normalize().unwrap_or(Millisecond::MAX);
+        let nanos = (ms.millis as u32 * 1_000 + ms.micros as u32) * 1_000 + ms.nanos as u32;
+
+        let mut date = String::from("P");
+        if ms.years > 0 {
+            date += &format!("{}Y", ms.years);
+        }
+        if ms.days > 0 {
+            date += &format!("{}D", ms.days);
+        }
+
+        let mut time = String::new();
+        if ms.hours > 0 {
+            time += &format!("{}H", ms.hours);
+        }
+        if ms.minutes > 0 {
+            time += &format!("{}M", ms.minutes);
+        }
+        if ms.seconds > 0 || nanos > 0 || (time.is_empty() && date.len() == 1) {
+            time += &format!("{}S", with_nanos_fraction(&ms.seconds, nanos, None));
+        }
+
+        if time.is_empty() {
+            date
+        } else {
+            format!("{date}T{time}")
+        }
+    }
+
+    /// Parses an ISO 8601 duration, e.g. `P1Y17DT5H10M48.5S`. Designators are case-insensitive,
+    /// and both `.` and `,` are accepted as the decimal separator of the last component.
+    ///
+    /// A week (`W`) is converted into 7 days and a year (`Y`) into 365 days, the same as
+    /// in `from_days`. Months have no fixed length, so a month (`M` before `T`) is rejected
+    /// with `ParseErrorKind::UnsupportedUnit`. Negative durations are rejected as well.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, ParseErrorKind};
+    /// let ms = Millisecond::from_iso8601("P1Y17DT5H10M48.5S").unwrap();
+    /// assert_eq!(ms, Millisecond::from_millis(33023448500));
+    ///
+    /// let ms = Millisecond::from_iso8601("P2W").unwrap();
+    /// assert_eq!(ms, Millisecond::from_days(14));
+    ///
+    /// let err = Millisecond::from_iso8601("P1M").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::UnsupportedUnit);
+    /// ```
+    pub fn from_iso8601(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        if !s.eat("P") && !s.eat("p") {
+            return Err(ParseError::new(ParseErrorKind::UnexpectedToken, 0));
+        }
+        if s.is_end() {
+            return Err(ParseError::new(ParseErrorKind::Empty, s.pos()));
+        }
+
+        let mut total: u128 = 0;
+        let mut in_time = false;
+        // the position of the last designator, as components must come in order
+        let mut last = 0;
+        let mut has_fraction = false;
+        while !s.is_end() {
+            if !in_time && (s.eat("T") || s.eat("t")) {
+                in_time = true;
+                if s.is_end() {
+                    return Err(ParseError::new(ParseErrorKind::InvalidNumber, s.pos()));
+                }
+                continue;
+            }
+
+            let start = s.pos();
+            if has_fraction {
+                return Err(ParseError::new(ParseErrorKind::UnexpectedToken, start));
+            }
+            let number = s.decimal_with_separators(&[".", ","])?;
+            let unit_start = s.pos();
+            let (order, nanos) = match (in_time, s.next_char().map(|c| c.to_ascii_uppercase())) {
+                (false, Some('Y')) => (1, Unit::Years.nanos()),
+                (false, Some('M')) => {
+                    return Err(ParseError::new(ParseErrorKind::UnsupportedUnit, unit_start))
+                }
+                (false, Some('W')) => (2, 7 * Unit::Days.nanos()),
+                (false, Some('D')) => (3, Unit::Days.nanos()),
+                (true, Some('H')) => (4, Unit::Hours.nanos()),
+                (true, Some('M')) => (5, Unit::Minutes.nanos()),
+                (true, Some('S')) => (6, Unit::Seconds.nanos()),
+                (_, None) => return Err(ParseError::new(ParseErrorKind::MissingUnit, unit_start)),
+                (_, Some(_)) => {
+                    return Err(ParseError::new(ParseErrorKind::UnknownUnit, unit_start))
+                }
+            };
+            if order <= last {
+                return Err(ParseError::new(ParseErrorKind::UnexpectedToken, start));
+            }
+            last = order;
+            has_fraction = number.has_fraction();
+            total = add_nanos(total, number.scale(nanos), start)?;
+        }
+        Ok(Millisecond::from_nanos(total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_iso8601() {
+        let cases = [
+            (Millisecond::ZERO, "PT0S"),
+            (Millisecond::from_nanos(1), "PT0.000000001S"),
+            (Millisecond::from_millis(1_500), "PT1.5S"),
+            (Millisecond::from_minutes(1), "PT1M"),
+            (Millisecond::from_hours(25), "P1DT1H"),
+            (Millisecond::from_days(366), "P1Y1D"),
+            (Millisecond::from_millis(33023448500), "P1Y17DT5H10M48.5S"),
+            (Millisecond::from_millis(33023400500), "P1Y17DT5H10M0.5S"),
+        ];
+        for (ms, expected) in cases {
+            assert_eq!(ms.to_iso8601(), expected);
+            assert_eq!(Millisecond::from_iso8601(expected), Ok(ms), "{expected}");
+        }
+    }
+    #[test]
+    fn should_parse_iso8601() {
+        let cases = [
+            ("PT36H", Millisecond::from_hours(36)),
+            ("PT90M", Millisecond::from_minutes(90)),
+            ("P1W", Millisecond::from_days(7)),
+            ("P1W1D", Millisecond::from_days(8)),
+            ("PT1,5S", Millisecond::from_millis(1_500)),
+            ("PT0.5H", Millisecond::from_minutes(30)),
+            ("P0.5Y", Millisecond::from_hours(365 * 12)),
+            ("p1dt1h", Millisecond::from_hours(25)),
+            ("P0D", Millisecond::ZERO),
+        ];
+        for (k, v) in cases {
+            assert_eq!(Millisecond::from_iso8601(k), Ok(v), "{k}");
+        }
+    }
+    #[test]
+    fn should_reject_invalid_iso8601() {
+        let cases = [
+            ("", ParseErrorKind::UnexpectedToken, 0),
+            ("1D", ParseErrorKind::UnexpectedToken, 0),
+            ("-P1D", ParseErrorKind::UnexpectedToken, 0),
+            ("P", ParseErrorKind::Empty, 1),
+            ("PT", ParseErrorKind::InvalidNumber, 2),
+            ("P1DT", ParseErrorKind::InvalidNumber, 4),
+            ("P1M", ParseErrorKind::UnsupportedUnit, 2),
+            ("P1", ParseErrorKind::MissingUnit, 2),
+            ("P1H", ParseErrorKind::UnknownUnit, 2),
+            ("PT1D", ParseErrorKind::UnknownUnit, 3),
+            ("P1D1Y", ParseErrorKind::UnexpectedToken, 3),
+            ("PT1S1S", ParseErrorKind::UnexpectedToken, 4),
+            ("PT1.5M1S", ParseErrorKind::UnexpectedToken, 6),
+            ("P1DT1H ", ParseErrorKind::InvalidNumber, 6),
+            ("P18446744073709551616Y", ParseErrorKind::Overflow, 1),
+        ];
+        for (k, kind, offset) in cases {
+            assert_eq!(
+                Millisecond::from_iso8601(k),
+                Err(ParseError::new(kind, offset)),
+                "{k}"
+            );
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::formatter::with_nanos_fraction;
+use crate::parser::{to_unsigned, Scanner};
+use crate::{Millisecond, SignedMillisecond, Unit};
+
+/// The longest input accepted by the `ms` package.
+const MAX_LENGTH: usize = 100;
+
+/// The units of the `ms` package in nanoseconds, where a year is 365.25 days.
+const UNITS: [(&[&str], u128); 7] = [
+    (&["years", "year", "yrs", "yr", "y"], 31_557_600_000_000_000),
+    (&["weeks", "week", "w"], 604_800_000_000_000),
+    (&["days", "day", "d"], 86_400_000_000_000),
+    (&["hours", "hour", "hrs", "hr", "h"], 3_600_000_000_000),
+    (&["minutes", "minute", "mins", "min", "m"], 60_000_000_000),
+    (&["seconds", "second", "secs", "sec", "s"], 1_000_000_000),
+    (
+        &["milliseconds", "millisecond", "msecs", "msec", "ms"],
+        1_000_000,
+    ),
+];
+
+/// The units written by the `ms` package, from the most significant one.
+const FORMAT_UNITS: [(&str, &str, u128); 4] = [
+    ("d", "day", 86_400_000_000_000),
+    ("h", "hour", 3_600_000_000_000),
+    ("m", "minute", 60_000_000_000),
+    ("s", "second", 1_000_000_000),
+];
+
+impl Millisecond {
+    /// Formats the instance the same as `ms(value)` of the `ms` npm package, e.g. `2d`,
+    /// `5h` or `1.5ms`. See `SignedMillisecond::to_js_ms_short`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::from_minutes(90).to_js_ms_short(), "2h");
+    /// assert_eq!(Millisecond::from_millis(100).to_js_ms_short(), "100ms");
+    /// ```
+    pub fn to_js_ms_short(&self) -> String {
+        SignedMillisecond::from(*self).to_js_ms_short()
+    }
+
+    /// Formats the instance the same as `ms(value, { long: true })` of the `ms` npm
+    /// package, e.g. `2 days` or `1 hour`. See `SignedMillisecond::to_js_ms_long`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::from_minutes(90).to_js_ms_long(), "2 hours");
+    /// assert_eq!(Millisecond::from_minutes(80).to_js_ms_long(), "1 hour");
+    /// ```
+    pub fn to_js_ms_long(&self) -> String {
+        SignedMillisecond::from(*self).to_js_ms_long()
+    }
+
+    /// Parses a duration the same as `ms(string)` of the `ms` npm package, e.g. `2 days`,
+    /// `1.5h` or `100`. Negative values are rejected with `ParseErrorKind::Negative`, see
+    /// `SignedMillisecond::from_js_ms` for them.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_js_ms("1.5h").unwrap();
+    /// assert_eq!(ms, Millisecond::from_minutes(90));
+    /// ```
+    pub fn from_js_ms(input: &str) -> Result<Self, ParseError> {
+        parse_js_ms(input).and_then(to_unsigned)
+    }
+}
+
+impl SignedMillisecond {
+    /// Formats the instance the same as `ms(value)` of the `ms` npm package, e.g. `2d`,
+    /// `-5h` or `1.5ms`. Only the most significant unit up to days is written, rounded
+    /// the same as `Math.round`, i.e. halves toward positive infinity.
+    /// ### example
+    /// ```rust
+    /// use millisecond::SignedMillisecond;
+    /// assert_eq!(SignedMillisecond::from_millis_i128(-259_200_000).to_js_ms_short(), "-3d");
+    /// assert_eq!(SignedMillisecond::from_millis_i128(-2_500).to_js_ms_short(), "-2s");
+    /// ```
+    pub fn to_js_ms_short(&self) -> String {
+        self.format_js_ms(false)
+    }
+
+    /// Formats the instance the same as `ms(value, { long: true })` of the `ms` npm
+    /// package, e.g. `2 days` or `-1 hour`. The same as the package, units are plural
+    /// from one and a half on, even when the value is rounded to one.
+    /// ### example
+    /// ```rust
+    /// use millisecond::SignedMillisecond;
+    /// assert_eq!(SignedMillisecond::from_millis_i128(-259_200_000).to_js_ms_long(), "-3 days");
+    /// assert_eq!(SignedMillisecond::from_millis_i128(1_500).to_js_ms_long(), "2 seconds");
+    /// ```
+    pub fn to_js_ms_long(&self) -> String {
+        self.format_js_ms(true)
+    }
+
+    /// Parses a duration the same as `ms(string)` of the `ms` npm package, e.g. `2 days`,
+    /// `1.5h`, `100` or `-3d`. Units are case-insensitive and may be separated from the
+    /// number by spaces, a number without a unit is in milliseconds, and a year is
+    /// 365.25 days. Inputs longer than 100 bytes are rejected, the same as the package.
+    /// ### example
+    /// ```rust
+    /// use millisecond::SignedMillisecond;
+    /// let ms = SignedMillisecond::from_js_ms("-3d").unwrap();
+    /// assert_eq!(ms, SignedMillisecond::from_millis_i128(-259_200_000));
+    ///
+    /// let ms = SignedMillisecond::from_js_ms("100").unwrap();
+    /// assert_eq!(ms, SignedMillisecond::from_millis_i128(100));
+    /// ```
+    pub fn from_js_ms(input: &str) -> Result<Self, ParseError> {
+        parse_js_ms(input).map(|(ms, _)| ms)
+    }
+
+    fn format_js_ms(&self, long: bool) -> String {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let nanos = self.magnitude().as_nanos();
+        for (short, name, unit) in FORMAT_UNITS {
+            if nanos < unit {
+                continue;
+            }
+            // `Math.round` rounds halves up, which is toward zero for negative values
+            let half = 2 * (nanos % unit);
+            let round_up = half > unit || (half == unit && !self.is_negative());
+            let value = nanos / unit + round_up as u128;
+            return match long {
+                false => format!("{sign}{value}{short}"),
+                true if 2 * nanos >= 3 * unit => format!("{sign}{value} {name}s"),
+                true => format!("{sign}{value} {name}"),
+            };
+        }
+
+        let millis = nanos / Unit::Millis.nanos();
+        let fraction = (nanos % Unit::Millis.nanos()) as u32 * 1_000;
+        let millis = with_nanos_fraction(&millis, fraction, None);
+        let sep = if long { " " } else { "" };
+        format!("{sign}{millis}{sep}ms")
+    }
+}
+
+/// Parses a duration of the `ms` package, along with the offset of its sign.
+fn parse_js_ms(input: &str) -> Result<(SignedMillisecond, usize), ParseError> {
+    if input.len() > MAX_LENGTH {
+        return Err(ParseError::new(ParseErrorKind::UnexpectedToken, MAX_LENGTH));
+    }
+    let mut s = Scanner::new(input);
+    if s.is_end() {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0));
+    }
+    let sign = s.pos();
+    let negative = s.eat("-");
+    let start = s.pos();
+    let number = s.loose_decimal()?;
+    // unlike Go, a number cannot end with its dot
+    if input[..s.pos()].ends_with('.') {
+        return Err(ParseError::new(ParseErrorKind::InvalidNumber, start));
+    }
+
+    s.take_while(|c| c == ' ');
+    let unit_start = s.pos();
+    let nanos = match s.take_while(char::is_alphabetic) {
+        "" => Unit::Millis.nanos(),
+        unit => UNITS
+            .iter()
+            .find(|(names, _)| names.iter().any(|x| x.eq_ignore_ascii_case(unit)))
+            .map(|(_, nanos)| *nanos)
+            .ok_or(ParseError::new(ParseErrorKind::UnknownUnit, unit_start))?,
+    };
+    if !s.is_end() {
+        return Err(ParseError::new(ParseErrorKind::UnexpectedToken, s.pos()));
+    }
+    number
+        .scale(nanos)
+        .and_then(|x| Millisecond::try_from_nanos(x).ok())
+        .map(|x| (SignedMillisecond::new(negative, x), sign))
+        .ok_or(ParseError::new(ParseErrorKind::Overflow, start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_js_ms() {
+        let cases = [
+            (0, "0ms", "0 ms"),
+            (100, "100ms", "100 ms"),
+            (999, "999ms", "999 ms"),
+            (1_000, "1s", "1 second"),
+            (1_499, "1s", "1 second"),
+            (1_500, "2s", "2 seconds"),
+            (60_000, "1m", "1 minute"),
+            (80_000, "1m", "1 minute"),
+            (90_000, "2m", "2 minutes"),
+            (3_600_000, "1h", "1 hour"),
+            (86_400_000, "1d", "1 day"),
+            (604_800_000, "7d", "7 days"),
+            (31_557_600_000, "365d", "365 days"),
+            (-2_500, "-2s", "-2 seconds"),
+            (-3_500, "-3s", "-3 seconds"),
+            (-1_000, "-1s", "-1 second"),
+            (-100, "-100ms", "-100 ms"),
+            (-259_200_000, "-3d", "-3 days"),
+        ];
+        for (k, short, long) in cases {
+            let ms = SignedMillisecond::from_millis_i128(k);
+            assert_eq!(ms.to_js_ms_short(), short, "{k}");
+            assert_eq!(ms.to_js_ms_long(), long, "{k}");
+        }
+        let ms = SignedMillisecond::from_nanos_i128(-1_500_001);
+        assert_eq!(ms.to_js_ms_short(), "-1.500001ms");
+        assert_eq!(Millisecond::from_micros(2_500).to_js_ms_long(), "2.5 ms");
+    }
+    #[test]
+    fn should_parse_js_ms() {
+        let cases = [
+            ("100", 100_000_000),
+            ("1m", 60_000_000_000),
+            ("1h", 3_600_000_000_000),
+            ("2d", 172_800_000_000_000),
+            ("3w", 1_814_400_000_000_000),
+            ("1s", 1_000_000_000),
+            ("100ms", 100_000_000),
+            ("1y", 31_557_600_000_000_000),
+            ("1.5h", 5_400_000_000_000),
+            ("1   s", 1_000_000_000),
+            ("1.5H", 5_400_000_000_000),
+            (".5ms", 500_000),
+            ("1.5ms", 1_500_000),
+            ("53 milliseconds", 53_000_000),
+            ("17 msecs", 17_000_000),
+            ("1 sec", 1_000_000_000),
+            ("1 min", 60_000_000_000),
+            ("1 hr", 3_600_000_000_000),
+            ("2 days", 172_800_000_000_000),
+            ("1 week", 604_800_000_000_000),
+            ("1 year", 31_557_600_000_000_000),
+            ("1.5 Hours", 5_400_000_000_000),
+            ("-100ms", -100_000_000),
+            ("-1.5h", -5_400_000_000_000),
+            ("-.5h", -1_800_000_000_000),
+            ("-3d", -259_200_000_000_000),
+        ];
+        for (k, v) in cases {
+            let ms = SignedMillisecond::from_js_ms(k);
+            assert_eq!(ms, Ok(SignedMillisecond::from_nanos_i128(v)), "{k}");
+        }
+        assert_eq!(
+            Millisecond::from_js_ms("-1s"),
+            Err(ParseError::new(ParseErrorKind::Negative, 0))
+        );
+    }
+    #[test]
+    fn should_reject_invalid_js_ms() {
+        let long = "1".repeat(101);
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("☃", ParseErrorKind::InvalidNumber, 0),
+            ("10-.5", ParseErrorKind::UnexpectedToken, 2),
+            ("ms", ParseErrorKind::InvalidNumber, 0),
+            ("1.", ParseErrorKind::InvalidNumber, 0),
+            ("1.s", ParseErrorKind::InvalidNumber, 0),
+            (" 1s", ParseErrorKind::InvalidNumber, 0),
+            ("1s ", ParseErrorKind::UnexpectedToken, 2),
+            ("1\ts", ParseErrorKind::UnexpectedToken, 1),
+            ("1 fortnight", ParseErrorKind::UnknownUnit, 2),
+            ("1h 30m", ParseErrorKind::UnexpectedToken, 2),
+            (long.as_str(), ParseErrorKind::UnexpectedToken, 100),
+        ];
+        for (k, kind, offset) in cases {
+            assert_eq!(
+                SignedMillisecond::from_js_ms(k),
+                Err(ParseError::new(kind, offset)),
+                "{k}"
+            );
+        }
+    }
+}
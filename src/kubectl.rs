@@ -0,0 +1,127 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::{Millisecond, SignedMillisecond};
+
+impl Millisecond {
+    /// Formats the instance as an age in `kubectl get`, the same as `HumanDuration` in
+    /// Kubernetes, e.g. `45s`, `3m20s`, `5h`, `2d3h` or `120d`. Fewer units are shown as
+    /// the value grows, and years are only shown from 2 years on.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::from_secs(45).to_kubectl_age(), "45s");
+    /// assert_eq!(Millisecond::from_secs(200).to_kubectl_age(), "3m20s");
+    /// assert_eq!(Millisecond::from_minutes(315).to_kubectl_age(), "5h15m");
+    /// assert_eq!(Millisecond::from_hours(51).to_kubectl_age(), "2d3h");
+    /// assert_eq!(Millisecond::from_days(120).to_kubectl_age(), "120d");
+    /// ```
+    pub fn to_kubectl_age(&self) -> String {
+        let seconds = self.as_secs();
+        if seconds < 60 * 2 {
+            return format!("{seconds}s");
+        }
+        let minutes = seconds / 60;
+        if minutes < 10 {
+            return match seconds % 60 {
+                0 => format!("{minutes}m"),
+                s => format!("{minutes}m{s}s"),
+            };
+        }
+        if minutes < 60 * 3 {
+            return format!("{minutes}m");
+        }
+        let hours = minutes / 60;
+        if hours < 8 {
+            match minutes % 60 {
+                0 => format!("{hours}h"),
+                m => format!("{hours}h{m}m"),
+            }
+        } else if hours < 48 {
+            format!("{hours}h")
+        } else if hours < 24 * 8 {
+            match hours % 24 {
+                0 => format!("{}d", hours / 24),
+                h => format!("{}d{h}h", hours / 24),
+            }
+        } else if hours < 24 * 365 * 2 {
+            format!("{}d", hours / 24)
+        } else if hours < 24 * 365 * 8 {
+            match hours / 24 % 365 {
+                0 => format!("{}y", hours / 24 / 365),
+                d => format!("{}y{d}d", hours / 24 / 365),
+            }
+        } else {
+            format!("{}y", hours / 24 / 365)
+        }
+    }
+}
+
+impl SignedMillisecond {
+    /// Formats the instance like `Millisecond::to_kubectl_age`. The same as `HumanDuration`,
+    /// a clock skew of less than 2 seconds into the future is shown as `0s`, and a larger
+    /// one as `<invalid>`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::SignedMillisecond;
+    /// assert_eq!(SignedMillisecond::from_millis_i128(-1_900).to_kubectl_age(), "0s");
+    /// assert_eq!(SignedMillisecond::from_millis_i128(-2_000).to_kubectl_age(), "<invalid>");
+    /// ```
+    pub fn to_kubectl_age(&self) -> String {
+        match (self.is_negative(), self.magnitude().as_secs()) {
+            (false, _) => self.magnitude().to_kubectl_age(),
+            (true, 0..=1) => String::from("0s"),
+            (true, _) => String::from("<invalid>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_kubectl_age() {
+        let cases = [
+            (Millisecond::ZERO, "0s"),
+            (Millisecond::from_millis(1_999), "1s"),
+            (Millisecond::from_secs(119), "119s"),
+            (Millisecond::from_secs(120), "2m"),
+            (Millisecond::from_secs(200), "3m20s"),
+            (Millisecond::from_secs(599), "9m59s"),
+            (Millisecond::from_secs(600), "10m"),
+            (Millisecond::from_minutes(179), "179m"),
+            (Millisecond::from_minutes(180), "3h"),
+            (Millisecond::from_minutes(479), "7h59m"),
+            (Millisecond::from_hours(8), "8h"),
+            (Millisecond::from_hours(47), "47h"),
+            (Millisecond::from_hours(48), "2d"),
+            (Millisecond::from_hours(51), "2d3h"),
+            (Millisecond::from_hours(191), "7d23h"),
+            (Millisecond::from_days(8), "8d"),
+            (Millisecond::from_days(729), "729d"),
+            (Millisecond::from_days(730), "2y"),
+            (Millisecond::from_days(800), "2y70d"),
+            (Millisecond::from_days(2_919), "7y364d"),
+            (Millisecond::from_days(2_920), "8y"),
+            (Millisecond::from_days(3_000), "8y"),
+        ];
+        for (k, v) in cases {
+            assert_eq!(k.to_kubectl_age(), v, "{k}");
+        }
+    }
+    #[test]
+    fn should_format_signed_kubectl_age() {
+        let cases = [
+            (0, "0s"),
+            (-999, "0s"),
+            (-1_999, "0s"),
+            (-2_000, "<invalid>"),
+            (3_000, "3s"),
+        ];
+        for (k, v) in cases {
+            let ms = SignedMillisecond::from_millis_i128(k);
+            assert_eq!(ms.to_kubectl_age(), v, "{k}");
+        }
+    }
+}
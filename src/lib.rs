@@ -9,8 +9,36 @@
 #![no_std]
 extern crate alloc;
 
+pub use colon::ColonOptions;
+pub use error::{Error, ParseError, ParseErrorKind};
 pub use formatter::MillisecondPart;
+pub use locale::{Language, Locale, PluralCategory};
+pub use options::FormatOptions;
+pub use postgres::IntervalStyle;
+pub use rate::WithRemainder;
+pub use signed::SignedMillisecond;
 pub use splitter::Millisecond;
+pub use timecode::{FrameRate, Timecode};
+pub use unit::Unit;
 
+mod arithmetic;
+mod colon;
+mod dotnet;
+mod error;
 mod formatter;
+mod go;
+mod humantime;
+mod iso8601;
+mod js_ms;
+mod kubectl;
+mod locale;
+mod options;
+mod parser;
+mod postgres;
+mod prometheus;
+mod rate;
+mod signed;
 mod splitter;
+mod systemd;
+mod timecode;
+mod unit;
@@ -0,0 +1,399 @@
+use crate::Unit;
+
+/// The plural categories of the Unicode CLDR, which select the form of a unit name,
+/// e.g. `One` for `1 hour` and `Other` for `5 hours`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// Supplies the words and number formatting to render a `Millisecond` in a language.
+/// Only `unit_name` and `unit_abbreviation` are required; the rest default to English.
+/// ## Example
+/// ```rust
+/// use millisecond::{Locale, Millisecond, PluralCategory, Unit};
+///
+/// struct Pirate;
+///
+/// impl Locale for Pirate {
+///     fn unit_name(&self, unit: Unit, category: PluralCategory) -> &str {
+///         match (unit, category) {
+///             (Unit::Days, PluralCategory::One) => "sunrise",
+///             (Unit::Days, _) => "sunrises",
+///             _ => "moments",
+///         }
+///     }
+///     fn unit_abbreviation(&self, _unit: Unit) -> &str {
+///         "ish"
+///     }
+/// }
+///
+/// let ms = Millisecond::from_days(2);
+/// assert_eq!(ms.format_long_in(&Pirate), "2 sunrises");
+/// ```
+pub trait Locale {
+    /// Returns the name of `unit` in the form of `category`, e.g. `hours`.
+    fn unit_name(&self, unit: Unit, category: PluralCategory) -> &str;
+
+    /// Returns the abbreviation of `unit` used by the short form, e.g. `h`.
+    fn unit_abbreviation(&self, unit: Unit) -> &str;
+
+    /// Returns the plural category of a number with the integer part `int`, which has
+    /// a fraction when `has_fraction`. By default, only an integer one is `One`.
+    fn plural_category(&self, int: u64, has_fraction: bool) -> PluralCategory {
+        if int == 1 && !has_fraction {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    /// Returns the text between the parts, e.g. ` ` in `1h 5m`.
+    fn separator(&self) -> &str {
+        " "
+    }
+
+    /// Returns the text between a number and its unit, in the long form when `long`.
+    fn unit_separator(&self, long: bool) -> &str {
+        if long {
+            " "
+        } else {
+            ""
+        }
+    }
+
+    /// Returns the text between the integer part of a number and its fraction.
+    fn decimal_separator(&self) -> &str {
+        "."
+    }
+
+    /// Returns the character written for `digit`, which is from 0 to 9.
+    fn digit(&self, digit: u8) -> char {
+        (b'0' + digit) as char
+    }
+}
+
+/// The languages shipped with the crate, each of which implements `Locale`.
+/// ## Example
+/// ```rust
+/// use millisecond::{Language, Millisecond};
+///
+/// let ms = Millisecond::from_millis(33023448000);
+/// assert_eq!(
+///     ms.format_long_in(&Language::German),
+///     "1 Jahr 17 Tage 5 Stunden 10 Minuten 48 Sekunden"
+/// );
+/// assert_eq!(ms.format_short_in(&Language::Japanese), "1年17日5時間10分48秒");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    English,
+    German,
+    French,
+    Spanish,
+    Persian,
+    Russian,
+    Japanese,
+    Arabic,
+}
+
+/// The singular and plural names of the units, from years to nanoseconds.
+type Names = [(&'static str, &'static str); 8];
+
+const ENGLISH: Names = [
+    ("year", "years"),
+    ("day", "days"),
+    ("hour", "hours"),
+    ("minute", "minutes"),
+    ("second", "seconds"),
+    ("millisecond", "milliseconds"),
+    ("microsecond", "microseconds"),
+    ("nanosecond", "nanoseconds"),
+];
+
+const GERMAN: Names = [
+    ("Jahr", "Jahre"),
+    ("Tag", "Tage"),
+    ("Stunde", "Stunden"),
+    ("Minute", "Minuten"),
+    ("Sekunde", "Sekunden"),
+    ("Millisekunde", "Millisekunden"),
+    ("Mikrosekunde", "Mikrosekunden"),
+    ("Nanosekunde", "Nanosekunden"),
+];
+
+const FRENCH: Names = [
+    ("an", "ans"),
+    ("jour", "jours"),
+    ("heure", "heures"),
+    ("minute", "minutes"),
+    ("seconde", "secondes"),
+    ("milliseconde", "millisecondes"),
+    ("microseconde", "microsecondes"),
+    ("nanoseconde", "nanosecondes"),
+];
+
+const SPANISH: Names = [
+    ("año", "años"),
+    ("día", "días"),
+    ("hora", "horas"),
+    ("minuto", "minutos"),
+    ("segundo", "segundos"),
+    ("milisegundo", "milisegundos"),
+    ("microsegundo", "microsegundos"),
+    ("nanosegundo", "nanosegundos"),
+];
+
+/// Persian and Japanese nouns do not change after a number.
+const PERSIAN: [&str; 8] = [
+    "سال",
+    "روز",
+    "ساعت",
+    "دقیقه",
+    "ثانیه",
+    "میلی‌ثانیه",
+    "میکروثانیه",
+    "نانوثانیه",
+];
+
+const JAPANESE: [&str; 8] = [
+    "年",
+    "日",
+    "時間",
+    "分",
+    "秒",
+    "ミリ秒",
+    "マイクロ秒",
+    "ナノ秒",
+];
+
+/// The `One`, `Few` and `Many` forms of the units; fractions take the `Few` form.
+const RUSSIAN: [[&str; 3]; 8] = [
+    ["год", "года", "лет"],
+    ["день", "дня", "дней"],
+    ["час", "часа", "часов"],
+    ["минута", "минуты", "минут"],
+    ["секунда", "секунды", "секунд"],
+    ["миллисекунда", "миллисекунды", "миллисекунд"],
+    ["микросекунда", "микросекунды", "микросекунд"],
+    ["наносекунда", "наносекунды", "наносекунд"],
+];
+
+/// The `One`, `Two`, `Few` and `Many` forms of the units; the others take the `One` form.
+const ARABIC: [[&str; 4]; 8] = [
+    ["سنة", "سنتان", "سنوات", "سنة"],
+    ["يوم", "يومان", "أيام", "يومًا"],
+    ["ساعة", "ساعتان", "ساعات", "ساعة"],
+    ["دقيقة", "دقيقتان", "دقائق", "دقيقة"],
+    ["ثانية", "ثانيتان", "ثوانٍ", "ثانية"],
+    ["ملي ثانية", "ملي ثانيتان", "ملي ثوانٍ", "ملي ثانية"],
+    ["ميكرو ثانية", "ميكرو ثانيتان", "ميكرو ثوانٍ", "ميكرو ثانية"],
+    ["نانو ثانية", "نانو ثانيتان", "نانو ثوانٍ", "نانو ثانية"],
+];
+
+impl Locale for Language {
+    fn unit_name(&self, unit: Unit, category: PluralCategory) -> &str {
+        let i = unit as usize;
+        let one = category == PluralCategory::One;
+        match self {
+            Language::English => pick(ENGLISH[i], one),
+            Language::German => pick(GERMAN[i], one),
+            Language::French => pick(FRENCH[i], one),
+            Language::Spanish => pick(SPANISH[i], one),
+            Language::Persian => PERSIAN[i],
+            Language::Japanese => JAPANESE[i],
+            Language::Russian => match category {
+                PluralCategory::One => RUSSIAN[i][0],
+                PluralCategory::Many => RUSSIAN[i][2],
+                _ => RUSSIAN[i][1],
+            },
+            Language::Arabic => match category {
+                PluralCategory::Two => ARABIC[i][1],
+                PluralCategory::Few => ARABIC[i][2],
+                PluralCategory::Many => ARABIC[i][3],
+                _ => ARABIC[i][0],
+            },
+        }
+    }
+
+    fn unit_abbreviation(&self, unit: Unit) -> &str {
+        let abbreviations = match self {
+            Language::English => ["y", "d", "h", "m", "s", "ms", "µs", "ns"],
+            Language::German => ["J", "T", "Std.", "Min.", "s", "ms", "µs", "ns"],
+            Language::French => ["a", "j", "h", "min", "s", "ms", "µs", "ns"],
+            Language::Spanish => ["a", "d", "h", "min", "s", "ms", "µs", "ns"],
+            Language::Persian => PERSIAN,
+            Language::Russian => ["г.", "д.", "ч", "мин", "с", "мс", "мкс", "нс"],
+            Language::Japanese => JAPANESE,
+            Language::Arabic => ["سنة", "يوم", "س", "د", "ث", "ملي ث", "ميكرو ث", "نانو ث"],
+        };
+        abbreviations[unit as usize]
+    }
+
+    fn plural_category(&self, int: u64, has_fraction: bool) -> PluralCategory {
+        match self {
+            Language::English | Language::German | Language::Spanish => {
+                match int == 1 && !has_fraction {
+                    true => PluralCategory::One,
+                    false => PluralCategory::Other,
+                }
+            }
+            // both zero and one take the singular, with or without a fraction
+            Language::French if int <= 1 => PluralCategory::One,
+            Language::Russian if !has_fraction => match (int % 10, int % 100) {
+                (1, x) if x != 11 => PluralCategory::One,
+                (2..=4, x) if !(12..=14).contains(&x) => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+            Language::Arabic if !has_fraction => match (int, int % 100) {
+                (0, _) => PluralCategory::Zero,
+                (1, _) => PluralCategory::One,
+                (2, _) => PluralCategory::Two,
+                (_, 3..=10) => PluralCategory::Few,
+                (_, 11..=99) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            _ => PluralCategory::Other,
+        }
+    }
+
+    fn separator(&self) -> &str {
+        match self {
+            Language::Persian => " و ",
+            Language::Japanese => "",
+            Language::Arabic => " و",
+            _ => " ",
+        }
+    }
+
+    fn unit_separator(&self, long: bool) -> &str {
+        match self {
+            Language::English if !long => "",
+            Language::Japanese => "",
+            _ => " ",
+        }
+    }
+
+    fn decimal_separator(&self) -> &str {
+        match self {
+            Language::English | Language::Japanese => ".",
+            Language::Persian | Language::Arabic => "٫",
+            _ => ",",
+        }
+    }
+
+    fn digit(&self, digit: u8) -> char {
+        let zero = match self {
+            Language::Persian => '۰',
+            Language::Arabic => '٠',
+            _ => '0',
+        };
+        char::from_u32(zero as u32 + digit as u32).unwrap_or(zero)
+    }
+}
+
+fn pick((one, other): (&'static str, &'static str), is_one: bool) -> &'static str {
+    if is_one {
+        one
+    } else {
+        other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Millisecond, SignedMillisecond};
+
+    #[test]
+    fn should_format_in_languages() {
+        let ms = Millisecond::from_millis(33023448000);
+        let cases = [
+            (
+                Language::English,
+                "1 year 17 days 5 hours 10 minutes 48 seconds",
+                "1y 17d 5h 10m 48s",
+            ),
+            (
+                Language::German,
+                "1 Jahr 17 Tage 5 Stunden 10 Minuten 48 Sekunden",
+                "1 J 17 T 5 Std. 10 Min. 48 s",
+            ),
+            (
+                Language::French,
+                "1 an 17 jours 5 heures 10 minutes 48 secondes",
+                "1 a 17 j 5 h 10 min 48 s",
+            ),
+            (
+                Language::Spanish,
+                "1 año 17 días 5 horas 10 minutos 48 segundos",
+                "1 a 17 d 5 h 10 min 48 s",
+            ),
+            (
+                Language::Persian,
+                "۱ سال و ۱۷ روز و ۵ ساعت و ۱۰ دقیقه و ۴۸ ثانیه",
+                "۱ سال و ۱۷ روز و ۵ ساعت و ۱۰ دقیقه و ۴۸ ثانیه",
+            ),
+            (
+                Language::Russian,
+                "1 год 17 дней 5 часов 10 минут 48 секунд",
+                "1 г. 17 д. 5 ч 10 мин 48 с",
+            ),
+            (
+                Language::Japanese,
+                "1年17日5時間10分48秒",
+                "1年17日5時間10分48秒",
+            ),
+            (
+                Language::Arabic,
+                "١ سنة و١٧ يومًا و٥ ساعات و١٠ دقائق و٤٨ ثانية",
+                "١ سنة و١٧ يوم و٥ س و١٠ د و٤٨ ث",
+            ),
+        ];
+        for (language, long, short) in cases {
+            assert_eq!(ms.format_long_in(&language), long, "{language:?}");
+            assert_eq!(ms.format_short_in(&language), short, "{language:?}");
+        }
+    }
+    #[test]
+    fn should_match_default_english_output() {
+        let cases = [0, 1, 999, 1_000, 1_500, 61_005, 3_600_000, 33023448250];
+        for k in cases {
+            let ms = Millisecond::from_millis(k);
+            assert_eq!(ms.format_long_in(&Language::English), ms.to_long_string());
+            assert_eq!(ms.format_short_in(&Language::English), ms.to_short_string());
+        }
+        let ms = SignedMillisecond::from_millis_i128(-1_500);
+        assert_eq!(ms.format_long_in(&Language::German), "-1,5 Sekunden");
+    }
+    #[test]
+    fn should_pluralize_by_language() {
+        let cases = [
+            (Language::French, 0, "0 milliseconde"),
+            (Language::French, 1_500, "1,5 seconde"),
+            (Language::French, 2_000, "2 secondes"),
+            (Language::Russian, 1_000, "1 секунда"),
+            (Language::Russian, 11_000, "11 секунд"),
+            (Language::Russian, 21_000, "21 секунда"),
+            (Language::Russian, 22_000, "22 секунды"),
+            (Language::Russian, 1_500, "1,5 секунды"),
+            (Language::Arabic, 0, "٠ ملي ثانية"),
+            (Language::Arabic, 2_000, "٢ ثانيتان"),
+            (Language::Arabic, 3_000, "٣ ثوانٍ"),
+            (Language::Arabic, 11_000, "١١ ثانية"),
+            (Language::Persian, 1_500, "۱٫۵ ثانیه"),
+            (Language::Spanish, 1_000, "1 segundo"),
+            (Language::Spanish, 1_500, "1,5 segundos"),
+        ];
+        for (language, k, v) in cases {
+            let ms = Millisecond::from_millis(k);
+            assert_eq!(ms.format_long_in(&language), v, "{language:?} {k}");
+        }
+    }
+}
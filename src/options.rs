@@ -0,0 +1,114 @@
+use crate::Unit;
+
+/// Options to customize how a `Millisecond` is formatted, modeled after the knobs of
+/// the `pretty-ms` package. The default options produce the same output as `Display`.
+/// ## Example
+/// ```rust
+/// use millisecond::{FormatOptions, Millisecond};
+///
+/// let ms = Millisecond::from_millis(33023448250);
+///
+/// let opts = FormatOptions::new().verbose(true).unit_count(2);
+/// assert_eq!(ms.format_with(&opts), "1 year 17 days");
+///
+/// let opts = FormatOptions::new().seconds_decimal_digits(1);
+/// assert_eq!(ms.format_with(&opts), "1y 17d 5h 10m 48.2s");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub(crate) verbose: bool,
+    pub(crate) compact: bool,
+    pub(crate) unit_count: Option<usize>,
+    pub(crate) seconds_decimal_digits: Option<u8>,
+    pub(crate) millis_decimal_digits: Option<u8>,
+    pub(crate) keep_decimals_on_whole_seconds: bool,
+    pub(crate) separate_millis: bool,
+    pub(crate) format_sub_millis: bool,
+    pub(crate) zero_unit: Unit,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            verbose: false,
+            compact: false,
+            unit_count: None,
+            seconds_decimal_digits: None,
+            millis_decimal_digits: None,
+            keep_decimals_on_whole_seconds: false,
+            separate_millis: false,
+            format_sub_millis: true,
+            zero_unit: Unit::Millis,
+        }
+    }
+}
+
+impl FormatOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses the long form of the units, e.g. `5 hours` instead of `5h`.
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Shows only the most significant unit, without any decimals, e.g. `1h` for `1h 10m`.
+    /// The dropped units are rounded the same way as with `unit_count`.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Shows at most `count` units, starting from the most significant one.
+    /// The dropped remainder is rounded into the last shown unit, carrying upward
+    /// when needed, e.g. `59m 59.9s` with one unit becomes `1h`.
+    pub fn unit_count(mut self, count: usize) -> Self {
+        self.unit_count = Some(count);
+        self
+    }
+
+    /// Writes the fraction of seconds with exactly `digits` digits.
+    /// By default, as many digits as needed are written.
+    pub fn seconds_decimal_digits(mut self, digits: u8) -> Self {
+        self.seconds_decimal_digits = Some(digits);
+        self
+    }
+
+    /// Writes the fraction of milliseconds with exactly `digits` digits.
+    /// It only applies when sub-milliseconds are not formatted as separate units.
+    /// By default, as many digits as needed are written.
+    pub fn millis_decimal_digits(mut self, digits: u8) -> Self {
+        self.millis_decimal_digits = Some(digits);
+        self
+    }
+
+    /// Writes the decimals of the seconds even when they are all zero, e.g. `13.0s`.
+    /// It only applies along with `seconds_decimal_digits`.
+    pub fn keep_decimals_on_whole_seconds(mut self, keep: bool) -> Self {
+        self.keep_decimals_on_whole_seconds = keep;
+        self
+    }
+
+    /// Shows milliseconds as their own unit instead of a fraction of the seconds,
+    /// e.g. `1s 300ms` instead of `1.3s`.
+    pub fn separate_millis(mut self, separate: bool) -> Self {
+        self.separate_millis = separate;
+        self
+    }
+
+    /// Shows microseconds and nanoseconds as their own units, which is the default.
+    /// When disabled, they are written as a fraction of the milliseconds instead,
+    /// e.g. `1.5ms` instead of `1ms 500µs`.
+    pub fn format_sub_millis(mut self, format: bool) -> Self {
+        self.format_sub_millis = format;
+        self
+    }
+
+    /// Uses `unit` to render a zero-length value, e.g. `0s` instead of `0ms`.
+    pub fn zero_unit(mut self, unit: Unit) -> Self {
+        self.zero_unit = unit;
+        self
+    }
+}
@@ -0,0 +1,380 @@
+use core::str::FromStr;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::{Millisecond, SignedMillisecond, Unit};
+
+/// A cursor over a duration string, shared by the parsers of every supported notation.
+pub(crate) struct Scanner<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    /// Returns the byte offset of the cursor in the input.
+    pub(crate) fn pos(&self) -> usize {
+        self.pos
+    }
+
+    pub(crate) fn is_end(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    pub(crate) fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Consumes `text` when the input continues with it.
+    pub(crate) fn eat(&mut self, text: &str) -> bool {
+        if self.input[self.pos..].starts_with(text) {
+            self.pos += text.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the next character without consuming it.
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    /// Consumes the next character.
+    pub(crate) fn next_char(&mut self) -> Option<char> {
+        let c = self.input[self.pos..].chars().next()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Consumes the longest run of characters matching `pred`.
+    pub(crate) fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &self.input[start..self.pos]
+    }
+
+    /// Consumes a number made of digits with an optional fraction, e.g. `1` or `1.5`.
+    pub(crate) fn decimal(&mut self) -> Result<Decimal, ParseError> {
+        self.decimal_with_separators(&["."])
+    }
+
+    /// Consumes a number like `decimal`, accepting any of `separators` before the fraction.
+    pub(crate) fn decimal_with_separators(
+        &mut self,
+        separators: &[&str],
+    ) -> Result<Decimal, ParseError> {
+        let start = self.pos;
+        let int = self.take_while(|c| c.is_ascii_digit());
+        if int.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::InvalidNumber, start));
+        }
+        let int = int
+            .parse()
+            .map_err(|_| ParseError::new(ParseErrorKind::Overflow, start))?;
+
+        let mut number = Decimal::from_int(int);
+        if separators.iter().any(|x| self.eat(x)) {
+            let fraction = self.take_while(|c| c.is_ascii_digit());
+            if fraction.is_empty() {
+                return Err(ParseError::new(ParseErrorKind::InvalidNumber, start));
+            }
+            number = number.with_fraction(fraction);
+        }
+        Ok(number)
+    }
+
+    /// Consumes a number like `decimal`, where either side of the `.` may be empty,
+    /// e.g. `.5` or `5.`, as accepted by Go and systemd.
+    pub(crate) fn loose_decimal(&mut self) -> Result<Decimal, ParseError> {
+        let start = self.pos;
+        let int = self.take_while(|c| c.is_ascii_digit());
+        let fraction = if self.eat(".") {
+            self.take_while(|c| c.is_ascii_digit())
+        } else {
+            ""
+        };
+        if int.is_empty() && fraction.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::InvalidNumber, start));
+        }
+        let int = match int {
+            "" => 0,
+            _ => int
+                .parse()
+                .map_err(|_| ParseError::new(ParseErrorKind::Overflow, start))?,
+        };
+        Ok(Decimal::from_int(int).with_fraction(fraction))
+    }
+}
+
+/// A non-negative decimal number, which is scaled into nanoseconds without going
+/// through floating point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Decimal {
+    int: u128,
+    fraction: u64,
+    scale: u64,
+}
+
+impl Decimal {
+    /// Digits of the fraction beyond this precision cannot add up to a nanosecond.
+    const MAX_FRACTION_DIGITS: usize = 18;
+
+    pub(crate) fn from_int(int: u128) -> Self {
+        Self {
+            int,
+            fraction: 0,
+            scale: 1,
+        }
+    }
+
+    /// Sets the fraction from its digits, e.g. `"05"` for `.05`.
+    pub(crate) fn with_fraction(self, digits: &str) -> Self {
+        let digits = &digits[..digits.len().min(Self::MAX_FRACTION_DIGITS)];
+        Self {
+            fraction: digits.parse().unwrap_or(0),
+            scale: 10u64.pow(digits.len() as u32),
+            ..self
+        }
+    }
+
+    pub(crate) fn has_fraction(&self) -> bool {
+        self.scale > 1
+    }
+
+    /// Multiplies the number by `unit` nanoseconds, truncating what is left below a nanosecond.
+    pub(crate) fn scale(&self, unit: u128) -> Option<u128> {
+        self.int
+            .checked_mul(unit)?
+            .checked_add(self.fraction as u128 * unit / self.scale as u128)
+    }
+}
+
+/// Sums the nanoseconds of the components parsed by `component` until the end of the
+/// input, which is required to have at least one of them.
+pub(crate) fn parse_sum(
+    s: &mut Scanner,
+    mut component: impl FnMut(&mut Scanner) -> Result<u128, ParseError>,
+) -> Result<u128, ParseError> {
+    s.skip_whitespace();
+    if s.is_end() {
+        return Err(ParseError::new(ParseErrorKind::Empty, s.pos()));
+    }
+    let mut total: u128 = 0;
+    while !s.is_end() {
+        let start = s.pos();
+        total = add_nanos(total, Some(component(s)?), start)?;
+        s.skip_whitespace();
+    }
+    Ok(total)
+}
+
+/// Adds the `nanos` of the token at `start` to `total`, returning `ParseErrorKind::Overflow`
+/// at the token when they are `None` or the sum does not fit into a `Millisecond`.
+pub(crate) fn add_nanos(
+    total: u128,
+    nanos: Option<u128>,
+    start: usize,
+) -> Result<u128, ParseError> {
+    nanos
+        .and_then(|x| x.checked_add(total))
+        .filter(|x| *x <= Millisecond::MAX.as_nanos())
+        .ok_or(ParseError::new(ParseErrorKind::Overflow, start))
+}
+
+/// Converts a parsed signed value into a `Millisecond`, rejecting negative ones at the
+/// offset of their `sign`.
+pub(crate) fn to_unsigned(
+    (ms, sign): (SignedMillisecond, usize),
+) -> Result<Millisecond, ParseError> {
+    Millisecond::try_from(ms).map_err(|_| ParseError::new(ParseErrorKind::Negative, sign))
+}
+
+/// Consumes a unit word, i.e. the longest run of letters.
+fn unit(s: &mut Scanner) -> Result<Unit, ParseError> {
+    let start = s.pos();
+    let unit = match s.take_while(char::is_alphabetic) {
+        "" => return Err(ParseError::new(ParseErrorKind::MissingUnit, start)),
+        "y" | "year" | "years" => Unit::Years,
+        "d" | "day" | "days" => Unit::Days,
+        "h" | "hour" | "hours" => Unit::Hours,
+        "m" | "minute" | "minutes" => Unit::Minutes,
+        "s" | "second" | "seconds" => Unit::Seconds,
+        "ms" | "millisecond" | "milliseconds" => Unit::Millis,
+        "µs" | "μs" | "us" | "microsecond" | "microseconds" => Unit::Micros,
+        "ns" | "nanosecond" | "nanoseconds" => Unit::Nanos,
+        _ => return Err(ParseError::new(ParseErrorKind::UnknownUnit, start)),
+    };
+    Ok(unit)
+}
+
+/// Parses the output of `to_short_string` and `to_long_string`, e.g. `1y 17d 5h 10m 48.5s`
+/// or `1 year 17 days`. Decimals are accepted in any unit, as well as `us` for `µs`, and
+/// any whitespace between the numbers and the units.
+/// ## Example
+/// ```rust
+/// use millisecond::{Millisecond, ParseErrorKind};
+///
+/// let ms: Millisecond = "1y 17d 5h 10m 48s".parse().unwrap();
+/// assert_eq!(ms, Millisecond::from_millis(33023448000));
+///
+/// let ms: Millisecond = "1 minute 1.005 seconds".parse().unwrap();
+/// assert_eq!(ms, Millisecond::from_millis(61_005));
+///
+/// let err = "1h 5x".parse::<Millisecond>().unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+/// assert_eq!(err.offset(), 4);
+/// ```
+impl FromStr for Millisecond {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut s = Scanner::new(s);
+        let nanos = parse_sum(&mut s, |s| {
+            let start = s.pos();
+            let number = s.decimal()?;
+            s.skip_whitespace();
+            number
+                .scale(unit(s)?.nanos())
+                .ok_or(ParseError::new(ParseErrorKind::Overflow, start))
+        })?;
+        // `parse_sum` keeps the total within `Millisecond::MAX`
+        Ok(Millisecond::from_nanos(nanos))
+    }
+}
+
+/// Parses the output of `SignedMillisecond::to_short_string` and `to_long_string`,
+/// which is the same as for `Millisecond` with an optional leading `-`.
+/// ## Example
+/// ```rust
+/// use millisecond::SignedMillisecond;
+///
+/// let ms: SignedMillisecond = "-1h 5m".parse().unwrap();
+/// assert_eq!(ms, SignedMillisecond::from_millis_i128(-3_900_000));
+/// ```
+impl FromStr for SignedMillisecond {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim_start();
+        match trimmed.strip_prefix('-') {
+            Some(rest) => rest
+                .parse()
+                .map(|x| SignedMillisecond::new(true, x))
+                .map_err(|e: ParseError| {
+                    let offset = s.len() - rest.len();
+                    ParseError::new(e.kind(), e.offset() + offset)
+                }),
+            None => s.parse().map(|x| SignedMillisecond::new(false, x)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FormatOptions;
+
+    #[test]
+    fn should_parse_short_and_long_forms() {
+        let cases = [
+            "1y 17d 5h 10m 48s",
+            "1 year 17 days 5 hours 10 minutes 48 seconds",
+            "1y17d5h10m48s",
+            "  1 y\t17d \n 5h 10 m 48s  ",
+        ];
+        for k in cases {
+            assert_eq!(k.parse(), Ok(Millisecond::from_millis(33023448000)), "{k}");
+        }
+    }
+    #[test]
+    fn should_parse_units_and_decimals() {
+        let cases = [
+            ("1.5s", Millisecond::from_millis(1_500)),
+            ("1.005 seconds", Millisecond::from_millis(1_005)),
+            ("0.5ms", Millisecond::from_micros(500)),
+            ("1.5h", Millisecond::from_minutes(90)),
+            ("0.5y", Millisecond::from_hours(365 * 12)),
+            (
+                "1 millisecond 2 microseconds 3 nanoseconds",
+                Millisecond::from_nanos(1_002_003),
+            ),
+            ("2µs 3us 4μs", Millisecond::from_micros(9)),
+            ("1.0000000009999s", Millisecond::from_nanos(1_000_000_000)),
+            ("0ms", Millisecond::ZERO),
+        ];
+        for (k, v) in cases {
+            assert_eq!(k.parse(), Ok(v), "{k}");
+        }
+    }
+    #[test]
+    fn should_round_trip_formatted_output() {
+        let cases = [0, 1, 999, 1_005, 61_050, 33023448000, 9007199254740991];
+        for k in cases {
+            let ms = Millisecond::from_nanos(k * 1_001_001);
+            assert_eq!(ms.to_short_string().parse(), Ok(ms), "{ms}");
+            assert_eq!(ms.to_long_string().parse(), Ok(ms), "{ms}");
+            let opts = FormatOptions::new()
+                .format_sub_millis(false)
+                .separate_millis(true);
+            let ms = Millisecond::from_micros(ms.as_micros() / 1_000 * 1_000 + 500);
+            assert_eq!(ms.format_with(&opts).parse(), Ok(ms), "{ms}");
+        }
+        assert_eq!(
+            Millisecond::MAX.to_short_string().parse(),
+            Ok(Millisecond::MAX)
+        );
+    }
+    #[test]
+    fn should_report_offset_of_bad_token() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("   ", ParseErrorKind::Empty, 3),
+            ("1h 5", ParseErrorKind::MissingUnit, 4),
+            ("1h 5 ", ParseErrorKind::MissingUnit, 5),
+            ("1h 5?", ParseErrorKind::MissingUnit, 4),
+            ("1h 5 weeks", ParseErrorKind::UnknownUnit, 5),
+            ("1h h", ParseErrorKind::InvalidNumber, 3),
+            ("1h -5m", ParseErrorKind::InvalidNumber, 3),
+            ("1.s", ParseErrorKind::InvalidNumber, 0),
+            (
+                "99999999999999999999999999999999999999999y",
+                ParseErrorKind::Overflow,
+                0,
+            ),
+            ("18446744073709551616y", ParseErrorKind::Overflow, 0),
+            ("1s 18446744073709551616y", ParseErrorKind::Overflow, 3),
+        ];
+        for (k, kind, offset) in cases {
+            assert_eq!(
+                k.parse::<Millisecond>(),
+                Err(ParseError::new(kind, offset)),
+                "{k}"
+            );
+        }
+    }
+    #[test]
+    fn should_parse_signed() {
+        let ms: SignedMillisecond = " -1 hour 5 minutes".parse().unwrap();
+        assert_eq!(ms, SignedMillisecond::from_millis_i128(-3_900_000));
+        assert_eq!(ms.to_long_string().parse(), Ok(ms));
+        assert_eq!("-0ms".parse(), Ok(SignedMillisecond::default()));
+        assert_eq!(
+            "-1h x".parse::<SignedMillisecond>(),
+            Err(ParseError::new(ParseErrorKind::InvalidNumber, 4))
+        );
+    }
+    #[test]
+    fn should_reject_negative_at_sign() {
+        let ms = SignedMillisecond::from_millis_i128(-1);
+        assert_eq!(
+            to_unsigned((ms, 3)),
+            Err(ParseError::new(ParseErrorKind::Negative, 3))
+        );
+        assert_eq!(to_unsigned((-ms, 3)), Ok(Millisecond::from_millis(1)));
+    }
+}
@@ -0,0 +1,402 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::formatter::with_nanos_fraction;
+use crate::parser::{add_nanos, parse_sum, Decimal, Scanner};
+use crate::{Millisecond, Unit};
+
+/// The output styles of a PostgreSQL `interval`, as set by its `IntervalStyle` setting.
+/// ## Example
+/// ```rust
+/// use millisecond::{IntervalStyle, Millisecond};
+///
+/// let ms = Millisecond::from_millis(33023448000);
+/// assert_eq!(ms.to_postgres_interval(IntervalStyle::Postgres), "382 days 05:10:48");
+/// assert_eq!(ms.to_postgres_interval(IntervalStyle::SqlStandard), "382 5:10:48");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IntervalStyle {
+    /// `382 days 05:10:48`, which is the default of PostgreSQL.
+    #[default]
+    Postgres,
+    /// `@ 382 days 5 hours 10 mins 48 secs`
+    PostgresVerbose,
+    /// `382 5:10:48`
+    SqlStandard,
+    /// `P382DT5H10M48S`
+    Iso8601,
+}
+
+impl Millisecond {
+    /// Formats the instance the same as a PostgreSQL `interval` is written in `style`,
+    /// down to microsecond precision. The value is written as days and a time of day,
+    /// so years never show up: PostgreSQL counts a year as 12 months, which have no
+    /// fixed length, and it never carries days into years, e.g. it writes `400 days`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{IntervalStyle, Millisecond};
+    /// let ms = Millisecond::from_millis(33023448500);
+    /// assert_eq!(
+    ///     ms.to_postgres_interval(IntervalStyle::PostgresVerbose),
+    ///     "@ 382 days 5 hours 10 mins 48.5 secs"
+    /// );
+    /// assert_eq!(
+    ///     ms.to_postgres_interval(IntervalStyle::Iso8601),
+    ///     "P382DT5H10M48.5S"
+    /// );
+    /// ```
+    pub fn to_postgres_interval(&self, style: IntervalStyle) -> String {
+        let ms = self.checked_normalize().unwrap_or(Millisecond::MAX);
+        let days = ms.as_nanos() / Unit::Days.nanos();
+        let (hours, minutes, seconds) = (ms.hours, ms.minutes, ms.seconds);
+        let micros = ms.millis as u32 * 1_000 + ms.micros as u32;
+        let has_time = hours > 0 || minutes > 0 || seconds > 0 || micros > 0;
+        // the seconds with their fraction, zero-padded to two digits when `fill` is set
+        let secs = |fill: bool| {
+            if fill {
+                with_nanos_fraction(&format!("{seconds:02}"), micros * 1_000, None)
+            } else {
+                with_nanos_fraction(&seconds, micros * 1_000, None)
+            }
+        };
+
+        match style {
+            IntervalStyle::Postgres => {
+                let mut parts = Vec::new();
+                if days > 0 {
+                    parts.push(with_unit(days, "day"));
+                }
+                if parts.is_empty() || has_time {
+                    parts.push(format!("{hours:02}:{minutes:02}:{}", secs(true)));
+                }
+                parts.join(" ")
+            }
+            IntervalStyle::PostgresVerbose => {
+                let mut text = String::from("@");
+                let parts = [
+                    (days, "day"),
+                    (hours as u128, "hour"),
+                    (minutes as u128, "min"),
+                ];
+                for (value, unit) in parts.into_iter().filter(|(x, _)| *x > 0) {
+                    text += &format!(" {}", with_unit(value, unit));
+                }
+                if seconds > 0 || micros > 0 {
+                    let plural = if seconds == 1 && micros == 0 { "" } else { "s" };
+                    text += &format!(" {} sec{plural}", secs(false));
+                }
+                if text.len() == 1 {
+                    text += " 0";
+                }
+                text
+            }
+            IntervalStyle::SqlStandard => {
+                let time = format!("{hours}:{minutes:02}:{}", secs(true));
+                match (days > 0, has_time) {
+                    (false, false) => String::from("0"),
+                    (true, _) => format!("{days} {time}"),
+                    (false, true) => time,
+                }
+            }
+            IntervalStyle::Iso8601 => {
+                let mut text = String::from("P");
+                if days > 0 {
+                    text += &format!("{days}D");
+                }
+                if has_time || days == 0 {
+                    text += "T";
+                    if hours > 0 {
+                        text += &format!("{hours}H");
+                    }
+                    if minutes > 0 {
+                        text += &format!("{minutes}M");
+                    }
+                    if seconds > 0 || micros > 0 || text.len() == 2 {
+                        text += &format!("{}S", secs(false));
+                    }
+                }
+                text
+            }
+        }
+    }
+
+    /// Parses a PostgreSQL `interval` in any of the styles of `IntervalStyle`, the same
+    /// as PostgreSQL accepts input in any style regardless of the setting, e.g.
+    /// `1 year 17 days 05:10:48`, `@ 1 year 17 days 5 hours`, `17 5:10:48` or `P1Y17D`.
+    ///
+    /// Years, including the ones of a SQL standard `y-m` and of `P1Y`, are read as 365
+    /// days, the same as in `from_years`. PostgreSQL counts a year as 12 months of 30
+    /// days, i.e. 360 days, so an input with years, such as the output of
+    /// `justify_interval`, means a different interval to PostgreSQL and does not
+    /// round-trip; `to_postgres_interval` never writes years.
+    ///
+    /// Months have no fixed length, so they are rejected with
+    /// `ParseErrorKind::UnsupportedUnit`, and negative intervals, including the ones
+    /// with `ago`, with `ParseErrorKind::Negative`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, ParseErrorKind};
+    /// let ms = Millisecond::from_postgres_interval("1 year 17 days 05:10:48").unwrap();
+    /// assert_eq!(ms, Millisecond::from_millis(33023448000));
+    ///
+    /// let ms = Millisecond::from_postgres_interval("@ 5 hours 10 mins").unwrap();
+    /// assert_eq!(ms, Millisecond::from_minutes(310));
+    ///
+    /// let err = Millisecond::from_postgres_interval("1 mon").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::UnsupportedUnit);
+    /// ```
+    pub fn from_postgres_interval(input: &str) -> Result<Self, ParseError> {
+        let trimmed = input.trim_start();
+        if trimmed.starts_with(['P', 'p']) {
+            let offset = input.len() - trimmed.len();
+            return Millisecond::from_iso8601(trimmed.trim_end())
+                .map_err(|e| ParseError::new(e.kind(), e.offset() + offset));
+        }
+
+        let mut s = Scanner::new(input);
+        s.skip_whitespace();
+        s.eat("@");
+        let nanos = parse_sum(&mut s, |s| {
+            let start = s.pos();
+            if s.eat("-") || s.eat("ago") {
+                return Err(ParseError::new(ParseErrorKind::Negative, start));
+            }
+            s.eat("+");
+            let number_start = s.pos();
+            let number = s.decimal()?;
+            if s.eat(":") {
+                return time(s, number, number_start);
+            }
+            if s.eat("-") {
+                return year_month(s, number, number_start);
+            }
+
+            s.skip_whitespace();
+            let unit_start = s.pos();
+            let nanos = match s.take_while(char::is_alphabetic) {
+                // a number before a time of day is a number of days, e.g. `17 5:10:48`
+                "" if is_time(&input[unit_start..]) => Unit::Days.nanos(),
+                "" => Unit::Seconds.nanos(),
+                "year" | "years" | "yr" | "yrs" | "y" => Unit::Years.nanos(),
+                "mon" | "mons" | "month" | "months" => {
+                    return Err(ParseError::new(ParseErrorKind::UnsupportedUnit, unit_start))
+                }
+                "week" | "weeks" | "w" => 7 * Unit::Days.nanos(),
+                "day" | "days" | "d" => Unit::Days.nanos(),
+                "hour" | "hours" | "hr" | "hrs" | "h" => Unit::Hours.nanos(),
+                "minute" | "minutes" | "min" | "mins" | "m" => Unit::Minutes.nanos(),
+                "second" | "seconds" | "sec" | "secs" | "s" => Unit::Seconds.nanos(),
+                "millisecond" | "milliseconds" | "msec" | "msecs" | "ms" => Unit::Millis.nanos(),
+                "microsecond" | "microseconds" | "usec" | "usecs" | "us" => Unit::Micros.nanos(),
+                "ago" => return Err(ParseError::new(ParseErrorKind::Negative, unit_start)),
+                _ => return Err(ParseError::new(ParseErrorKind::UnknownUnit, unit_start)),
+            };
+            number
+                .scale(nanos)
+                .ok_or(ParseError::new(ParseErrorKind::Overflow, number_start))
+        })?;
+        Ok(Millisecond::from_nanos(nanos))
+    }
+}
+
+/// Writes `value` followed by `unit`, which is made plural unless `value` is one.
+fn with_unit(value: u128, unit: &str) -> String {
+    let plural = if value == 1 { "" } else { "s" };
+    format!("{value} {unit}{plural}")
+}
+
+/// Returns `true` when `rest` starts with a time of day, e.g. `+5:10:48`.
+fn is_time(rest: &str) -> bool {
+    let rest = rest.strip_prefix('+').unwrap_or(rest);
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    digits > 0 && rest[digits..].starts_with(':')
+}
+
+/// Consumes the rest of a time of day `hh:mm[:ss[.ffffff]]` after its hours.
+fn time(s: &mut Scanner, hours: Decimal, start: usize) -> Result<u128, ParseError> {
+    let hours = integer(hours, start, u128::MAX)?;
+    let minutes_start = s.pos();
+    let minutes = integer(s.decimal()?, minutes_start, 59)?;
+    let mut nanos = add_nanos(0, hours.checked_mul(Unit::Hours.nanos()), start)?;
+    nanos = add_nanos(nanos, Some(minutes * Unit::Minutes.nanos()), minutes_start)?;
+    if s.eat(":") {
+        let seconds_start = s.pos();
+        let seconds = s.decimal()?;
+        if seconds.scale(1).map_or(true, |x| x > 59) {
+            let kind = ParseErrorKind::ComponentOutOfRange;
+            return Err(ParseError::new(kind, seconds_start));
+        }
+        nanos = add_nanos(nanos, seconds.scale(Unit::Seconds.nanos()), seconds_start)?;
+    }
+    Ok(nanos)
+}
+
+/// Consumes the months of a SQL standard `y-m` after its years, which must be zero.
+fn year_month(s: &mut Scanner, years: Decimal, start: usize) -> Result<u128, ParseError> {
+    let years = integer(years, start, u128::MAX)?;
+    let months_start = s.pos();
+    if integer(s.decimal()?, months_start, 11)? > 0 {
+        return Err(ParseError::new(
+            ParseErrorKind::UnsupportedUnit,
+            months_start,
+        ));
+    }
+    years
+        .checked_mul(Unit::Years.nanos())
+        .ok_or(ParseError::new(ParseErrorKind::Overflow, start))
+}
+
+/// Returns `number` when it has no fraction and is at most `max`.
+fn integer(number: Decimal, start: usize, max: u128) -> Result<u128, ParseError> {
+    if number.has_fraction() {
+        return Err(ParseError::new(ParseErrorKind::InvalidNumber, start));
+    }
+    match number.scale(1) {
+        Some(x) if x <= max => Ok(x),
+        _ => Err(ParseError::new(ParseErrorKind::ComponentOutOfRange, start)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_postgres_interval() {
+        let cases = [
+            (0, ["00:00:00", "@ 0", "0", "PT0S"]),
+            (
+                1_500_000,
+                ["00:00:01.5", "@ 1.5 secs", "0:00:01.5", "PT1.5S"],
+            ),
+            (1_000_000, ["00:00:01", "@ 1 sec", "0:00:01", "PT1S"]),
+            (
+                3_723_000_001,
+                [
+                    "01:02:03.000001",
+                    "@ 1 hour 2 mins 3.000001 secs",
+                    "1:02:03.000001",
+                    "PT1H2M3.000001S",
+                ],
+            ),
+            (86_400_000_000, ["1 day", "@ 1 day", "1 0:00:00", "P1D"]),
+            (
+                31_536_000_000_000,
+                ["365 days", "@ 365 days", "365 0:00:00", "P365D"],
+            ),
+            (
+                34_560_000_000_000,
+                ["400 days", "@ 400 days", "400 0:00:00", "P400D"],
+            ),
+            (
+                33_023_448_000_000,
+                [
+                    "382 days 05:10:48",
+                    "@ 382 days 5 hours 10 mins 48 secs",
+                    "382 5:10:48",
+                    "P382DT5H10M48S",
+                ],
+            ),
+            (
+                63_159_060_000_000,
+                [
+                    "731 days 00:11:00",
+                    "@ 731 days 11 mins",
+                    "731 0:11:00",
+                    "P731DT11M",
+                ],
+            ),
+        ];
+        let styles = [
+            IntervalStyle::Postgres,
+            IntervalStyle::PostgresVerbose,
+            IntervalStyle::SqlStandard,
+            IntervalStyle::Iso8601,
+        ];
+        for (k, expected) in cases {
+            let ms = Millisecond::from_micros(k);
+            for (style, v) in styles.into_iter().zip(expected) {
+                assert_eq!(ms.to_postgres_interval(style), v, "{style:?}");
+                assert_eq!(Millisecond::from_postgres_interval(v), Ok(ms), "{v}");
+            }
+        }
+        // years are folded into days, as PostgreSQL counts a year as 12 months
+        let ms = Millisecond::from_years(u64::MAX);
+        assert_eq!(
+            ms.to_postgres_interval(IntervalStyle::Postgres),
+            "6733061586903986339475 days"
+        );
+        let ms = Millisecond::from_nanos(1_999);
+        assert_eq!(
+            ms.to_postgres_interval(IntervalStyle::Postgres),
+            "00:00:00.000001"
+        );
+        assert_eq!(
+            ms.to_postgres_interval(IntervalStyle::Iso8601),
+            "PT0.000001S"
+        );
+    }
+    #[test]
+    fn should_parse_postgres_interval() {
+        let cases = [
+            ("5", Millisecond::from_secs(5)),
+            ("1:30", Millisecond::from_minutes(90)),
+            ("36:00:00", Millisecond::from_hours(36)),
+            ("1 week 2 days", Millisecond::from_days(9)),
+            ("1.5 hours", Millisecond::from_minutes(90)),
+            ("3 ms 4 us", Millisecond::from_micros(3_004)),
+            ("  2 day 01:00  ", Millisecond::from_hours(49)),
+            ("+1-0", Millisecond::from_years(1)),
+            ("@ 1 minute 1 second", Millisecond::from_secs(61)),
+        ];
+        for (k, v) in cases {
+            assert_eq!(Millisecond::from_postgres_interval(k), Ok(v), "{k}");
+        }
+    }
+    #[test]
+    fn should_read_years_as_365_days() {
+        for k in ["1 year", "1 yr", "+1-0", "P1Y", "@ 1 year"] {
+            let ms = Millisecond::from_postgres_interval(k);
+            assert_eq!(ms, Ok(Millisecond::from_days(365)), "{k}");
+            assert_ne!(ms, Ok(Millisecond::from_days(360)), "{k}");
+        }
+        let ms = Millisecond::from_postgres_interval("1 year 5 days").unwrap();
+        assert_eq!(ms.to_postgres_interval(IntervalStyle::Postgres), "370 days");
+    }
+    #[test]
+    fn should_reject_invalid_postgres_interval() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("@", ParseErrorKind::Empty, 1),
+            ("1 mon", ParseErrorKind::UnsupportedUnit, 2),
+            ("1-2", ParseErrorKind::UnsupportedUnit, 2),
+            ("1-12", ParseErrorKind::ComponentOutOfRange, 2),
+            ("-1 day", ParseErrorKind::Negative, 0),
+            ("@ 1 day ago", ParseErrorKind::Negative, 8),
+            ("00:60:00", ParseErrorKind::ComponentOutOfRange, 3),
+            ("00:00:60", ParseErrorKind::ComponentOutOfRange, 6),
+            ("1.5:00", ParseErrorKind::InvalidNumber, 0),
+            ("1 fortnight", ParseErrorKind::UnknownUnit, 2),
+            (" P1M", ParseErrorKind::UnsupportedUnit, 3),
+            (
+                "94522879700260684295381835:59:59",
+                ParseErrorKind::Overflow,
+                0,
+            ),
+            (
+                "18446744073709551615 years 8760:00:00",
+                ParseErrorKind::Overflow,
+                27,
+            ),
+        ];
+        for (k, kind, offset) in cases {
+            assert_eq!(
+                Millisecond::from_postgres_interval(k),
+                Err(ParseError::new(kind, offset)),
+                "{k}"
+            );
+        }
+    }
+}
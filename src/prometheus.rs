@@ -0,0 +1,174 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parser::{add_nanos, Scanner};
+use crate::{Millisecond, Unit};
+
+/// The units of a Prometheus duration in milliseconds, from the most significant one,
+/// and whether they are only written when the remainder divides evenly.
+const UNITS: [(&str, u128, bool); 7] = [
+    ("y", 365 * 86_400_000, true),
+    ("w", 7 * 86_400_000, true),
+    ("d", 86_400_000, false),
+    ("h", 3_600_000, false),
+    ("m", 60_000, false),
+    ("s", 1_000, false),
+    ("ms", 1, false),
+];
+
+impl Millisecond {
+    /// Formats the instance the same as `model.Duration.String()` in Prometheus, e.g.
+    /// `1y2w3d4h5m6s7ms`, truncating what is below a millisecond. The same as Prometheus,
+    /// years and weeks are only written when they divide the rest evenly, so 90 days
+    /// is written as `90d` rather than `12w6d`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::from_days(14).to_prometheus_duration(), "2w");
+    /// assert_eq!(Millisecond::from_days(90).to_prometheus_duration(), "90d");
+    /// assert_eq!(Millisecond::from_millis(61_500).to_prometheus_duration(), "1m1s500ms");
+    /// assert_eq!(Millisecond::ZERO.to_prometheus_duration(), "0s");
+    /// ```
+    pub fn to_prometheus_duration(&self) -> String {
+        let mut ms = self.as_millis();
+        if ms == 0 {
+            return String::from("0s");
+        }
+        let mut text = String::new();
+        for (suffix, unit, exact) in UNITS {
+            if exact && ms % unit != 0 {
+                continue;
+            }
+            let value = ms / unit;
+            if value > 0 {
+                text += &format!("{value}{suffix}");
+                ms -= value * unit;
+            }
+        }
+        text
+    }
+
+    /// Parses a duration the same as `model.ParseDuration` in Prometheus, e.g. `1y2w3d4h5m6s7ms`
+    /// or `5m`, with a year of 365 days and a week of 7 days. Units must appear from the
+    /// most significant one and at most once, numbers are integers, and `0` alone is
+    /// accepted. Unlike Prometheus, values beyond the range of an `int64` of nanoseconds
+    /// are accepted.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, ParseErrorKind};
+    /// let ms = Millisecond::from_prometheus_duration("1w2d").unwrap();
+    /// assert_eq!(ms, Millisecond::from_days(9));
+    ///
+    /// let err = Millisecond::from_prometheus_duration("5m1h").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::UnexpectedToken);
+    /// assert_eq!(err.offset(), 3);
+    /// ```
+    pub fn from_prometheus_duration(input: &str) -> Result<Self, ParseError> {
+        match input {
+            "" => return Err(ParseError::new(ParseErrorKind::Empty, 0)),
+            "0" => return Ok(Millisecond::ZERO),
+            _ => {}
+        }
+        let mut s = Scanner::new(input);
+        let mut total: u128 = 0;
+        // the index of the last unit in `UNITS`, as units must come in order
+        let mut last = None;
+        while !s.is_end() {
+            let start = s.pos();
+            let value = match s.take_while(|c| c.is_ascii_digit()) {
+                "" => return Err(ParseError::new(ParseErrorKind::InvalidNumber, start)),
+                digits => digits
+                    .parse::<u128>()
+                    .map_err(|_| ParseError::new(ParseErrorKind::Overflow, start))?,
+            };
+
+            let unit_start = s.pos();
+            let index = match s.take_while(|c| !c.is_ascii_digit()) {
+                "" => return Err(ParseError::new(ParseErrorKind::MissingUnit, unit_start)),
+                unit => UNITS
+                    .iter()
+                    .position(|(suffix, _, _)| *suffix == unit)
+                    .ok_or(ParseError::new(ParseErrorKind::UnknownUnit, unit_start))?,
+            };
+            if last.is_some_and(|x| x >= index) {
+                return Err(ParseError::new(ParseErrorKind::UnexpectedToken, unit_start));
+            }
+            last = Some(index);
+
+            let nanos = value.checked_mul(UNITS[index].1 * Unit::Millis.nanos());
+            total = add_nanos(total, nanos, start)?;
+        }
+        Ok(Millisecond::from_nanos(total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_prometheus_duration() {
+        let cases = [
+            (0, "0s"),
+            (1, "1ms"),
+            (1_000, "1s"),
+            (61_500, "1m1s500ms"),
+            (3_600_000, "1h"),
+            (86_400_000, "1d"),
+            (7 * 86_400_000, "1w"),
+            (90 * 86_400_000, "90d"),
+            (365 * 86_400_000, "1y"),
+            (33023448000, "382d5h10m48s"),
+            (
+                (365 + 14) * 86_400_000 + 3 * 86_400_000 + 4 * 3_600_000 + 306_007,
+                "382d4h5m6s7ms",
+            ),
+            ((365 + 14) * 86_400_000, "379d"),
+            (730 * 86_400_000, "2y"),
+        ];
+        for (k, v) in cases {
+            let ms = Millisecond::from_millis(k);
+            assert_eq!(ms.to_prometheus_duration(), v, "{k}");
+            assert_eq!(Millisecond::from_prometheus_duration(v), Ok(ms), "{v}");
+        }
+        let ms = Millisecond::from_micros(1_999);
+        assert_eq!(ms.to_prometheus_duration(), "1ms");
+    }
+    #[test]
+    fn should_parse_prometheus_duration() {
+        let cases = [
+            ("0", 0),
+            ("0s", 0),
+            ("1y2w3d4h5m6s7ms", 33_019_506_007),
+            ("2w", 1_209_600_000),
+            ("1h30m", 5_400_000),
+            ("250ms", 250),
+        ];
+        for (k, v) in cases {
+            let ms = Millisecond::from_prometheus_duration(k);
+            assert_eq!(ms, Ok(Millisecond::from_millis(v)), "{k}");
+        }
+    }
+    #[test]
+    fn should_reject_invalid_prometheus_duration() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("5", ParseErrorKind::MissingUnit, 1),
+            ("s", ParseErrorKind::InvalidNumber, 0),
+            ("1.5h", ParseErrorKind::UnknownUnit, 1),
+            ("5M", ParseErrorKind::UnknownUnit, 1),
+            ("1h 5m", ParseErrorKind::UnknownUnit, 1),
+            ("5m1h", ParseErrorKind::UnexpectedToken, 3),
+            ("1h1h", ParseErrorKind::UnexpectedToken, 3),
+            ("-5m", ParseErrorKind::InvalidNumber, 0),
+        ];
+        for (k, kind, offset) in cases {
+            assert_eq!(
+                Millisecond::from_prometheus_duration(k),
+                Err(ParseError::new(kind, offset)),
+                "{k}"
+            );
+        }
+    }
+}
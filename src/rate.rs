@@ -0,0 +1,191 @@
+use crate::{Error, Millisecond, Unit};
+
+/// The exact result of a conversion at a rate, which is `value` plus the fraction
+/// `remainder / divisor` of one of its units, i.e. of a nanosecond for a `Millisecond` and
+/// of a sample or a tick for a count.
+/// ### example
+/// ```rust
+/// use millisecond::Millisecond;
+///
+/// let ms = Millisecond::from_samples(1, 44_100).unwrap();
+/// assert_eq!(ms.value, Millisecond::from_nanos(22_675));
+/// assert_eq!((ms.remainder, ms.divisor), (32_500, 44_100));
+/// assert!(!ms.is_exact());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WithRemainder<T> {
+    pub value: T,
+    pub remainder: u64,
+    pub divisor: u64,
+}
+
+impl<T> WithRemainder<T> {
+    /// Returns true when there is no remainder, i.e. `value` is the exact result.
+    pub fn is_exact(&self) -> bool {
+        self.remainder == 0
+    }
+}
+
+impl Millisecond {
+    /// Creates a Millisecond instance from `count` audio samples at `rate_hz`, along with
+    /// the remainder below a nanosecond over `rate_hz`. Returns `Error::ZeroRate` when
+    /// `rate_hz` is zero.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Error, Millisecond};
+    /// let ms = Millisecond::from_samples(48_000, 48_000).unwrap();
+    /// assert_eq!(ms.value, Millisecond::from_secs(1));
+    /// assert!(ms.is_exact());
+    ///
+    /// assert_eq!(Millisecond::from_samples(1, 0), Err(Error::ZeroRate));
+    /// ```
+    pub fn from_samples(count: u64, rate_hz: u64) -> Result<WithRemainder<Self>, Error> {
+        from_cycles(count, rate_hz)
+    }
+
+    /// Returns the number of whole audio samples at `rate_hz` in the instance, along with
+    /// the remainder below a sample over a billion. Returns `Error::ZeroRate` when
+    /// `rate_hz` is zero, and `Error::Overflow` when the count does not fit into a `u64`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Error, Millisecond};
+    /// let samples = Millisecond::from_millis(1_500).to_samples(44_100).unwrap();
+    /// assert_eq!(samples.value, 66_150);
+    /// assert!(samples.is_exact());
+    ///
+    /// let samples = Millisecond::from_nanos(22_675).to_samples(44_100).unwrap();
+    /// assert_eq!(samples.value, 0);
+    /// assert_eq!((samples.remainder, samples.divisor), (999_967_500, 1_000_000_000));
+    /// assert_eq!(Millisecond::MAX.to_samples(48_000), Err(Error::Overflow));
+    /// ```
+    pub fn to_samples(&self, rate_hz: u64) -> Result<WithRemainder<u64>, Error> {
+        to_cycles(self, rate_hz)
+    }
+
+    /// Creates a Millisecond instance from `count` ticks of a clock at `freq_hz`, e.g. CPU
+    /// cycles. See `from_samples`, and `try_from_dotnet_ticks` for .NET ticks.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_clock_ticks(3_000_000_001, 3_000_000_000).unwrap();
+    /// assert_eq!(ms.value, Millisecond::from_secs(1));
+    /// assert_eq!((ms.remainder, ms.divisor), (1_000_000_000, 3_000_000_000));
+    /// ```
+    pub fn from_clock_ticks(count: u64, freq_hz: u64) -> Result<WithRemainder<Self>, Error> {
+        from_cycles(count, freq_hz)
+    }
+
+    /// Returns the number of whole ticks of a clock at `freq_hz` in the instance. See
+    /// `to_samples`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ticks = Millisecond::from_nanos(1_001).to_clock_ticks(3_000_000_000).unwrap();
+    /// assert_eq!(ticks.value, 3_003);
+    /// assert!(ticks.is_exact());
+    /// ```
+    pub fn to_clock_ticks(&self, freq_hz: u64) -> Result<WithRemainder<u64>, Error> {
+        to_cycles(self, freq_hz)
+    }
+}
+
+fn from_cycles(count: u64, freq_hz: u64) -> Result<WithRemainder<Millisecond>, Error> {
+    if freq_hz == 0 {
+        return Err(Error::ZeroRate);
+    }
+    // at most `u64::MAX` seconds, which fits into a `Millisecond`
+    let total = count as u128 * Unit::Seconds.nanos();
+    let divisor = freq_hz as u128;
+    Ok(WithRemainder {
+        value: Millisecond::from_nanos(total / divisor),
+        remainder: (total % divisor) as u64,
+        divisor: freq_hz,
+    })
+}
+
+fn to_cycles(ms: &Millisecond, freq_hz: u64) -> Result<WithRemainder<u64>, Error> {
+    if freq_hz == 0 {
+        return Err(Error::ZeroRate);
+    }
+    let total = ms
+        .as_nanos()
+        .checked_mul(freq_hz as u128)
+        .ok_or(Error::Overflow)?;
+    let divisor = Unit::Seconds.nanos();
+    Ok(WithRemainder {
+        value: u64::try_from(total / divisor).map_err(|_| Error::Overflow)?,
+        remainder: (total % divisor) as u64,
+        divisor: divisor as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with(value: u64, remainder: u64, divisor: u64) -> WithRemainder<u64> {
+        WithRemainder {
+            value,
+            remainder,
+            divisor,
+        }
+    }
+
+    #[test]
+    fn should_convert_samples() {
+        let cases = [
+            (0, 44_100, 0, 0),
+            (44_100, 44_100, 1_000_000_000, 0),
+            (1, 44_100, 22_675, 32_500),
+            (1, 48_000, 20_833, 16_000),
+            (3, 48_000, 62_500, 0),
+            (u64::MAX, 1, u64::MAX as u128 * 1_000_000_000, 0),
+        ];
+        for (count, rate, nanos, remainder) in cases {
+            let ms = Millisecond::from_nanos(nanos);
+            assert_eq!(
+                Millisecond::from_samples(count, rate),
+                Ok(WithRemainder {
+                    value: ms,
+                    remainder,
+                    divisor: rate,
+                }),
+                "{count}"
+            );
+            // the exact length round-trips when there is no remainder
+            if remainder == 0 {
+                assert_eq!(ms.to_samples(rate), Ok(with(count, 0, 1_000_000_000)));
+            }
+        }
+        let ms = Millisecond::from_nanos(20_833);
+        assert_eq!(
+            ms.to_samples(48_000),
+            Ok(with(0, 999_984_000, 1_000_000_000))
+        );
+    }
+    #[test]
+    fn should_convert_clock_ticks() {
+        let ms = Millisecond::from_clock_ticks(7, 3_000_000_000).unwrap();
+        assert_eq!(ms.value, Millisecond::from_nanos(2));
+        assert_eq!((ms.remainder, ms.divisor), (1_000_000_000, 3_000_000_000));
+        assert_eq!(
+            ms.value.to_clock_ticks(3_000_000_000),
+            Ok(with(6, 0, 1_000_000_000))
+        );
+        let ms = Millisecond::from_secs(u64::MAX);
+        assert_eq!(ms.to_clock_ticks(1), Ok(with(u64::MAX, 0, 1_000_000_000)));
+        assert_eq!(ms.to_clock_ticks(2), Err(Error::Overflow));
+        assert_eq!(
+            Millisecond::MAX.to_clock_ticks(u64::MAX),
+            Err(Error::Overflow)
+        );
+    }
+    #[test]
+    fn should_reject_zero_rate() {
+        assert_eq!(Millisecond::from_samples(1, 0), Err(Error::ZeroRate));
+        assert_eq!(Millisecond::from_clock_ticks(1, 0), Err(Error::ZeroRate));
+        let ms = Millisecond::from_secs(1);
+        assert_eq!(ms.to_samples(0), Err(Error::ZeroRate));
+        assert_eq!(ms.to_clock_ticks(0), Err(Error::ZeroRate));
+    }
+}
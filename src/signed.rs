@@ -0,0 +1,298 @@
+use alloc::format;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::ops::Neg;
+
+use crate::{Error, FormatOptions, Locale, Millisecond};
+
+/// A signed counterpart of `Millisecond`, for values such as clock skews or deadline
+/// overruns which can be negative. It holds the magnitude as a `Millisecond` and a sign
+/// flag; a zero-length value is never negative.
+/// ## Example
+/// ```rust
+/// use millisecond::{Millisecond, SignedMillisecond};
+///
+/// let ms = SignedMillisecond::from_millis_i128(-3_900_000);
+///
+/// assert!(ms.is_negative());
+/// assert_eq!(ms.magnitude(), Millisecond::from_minutes(65));
+/// assert_eq!(ms.to_string(), "-1h 5m");
+/// assert_eq!(ms.to_long_string(), "-1 hour 5 minutes");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SignedMillisecond {
+    negative: bool,
+    magnitude: Millisecond,
+}
+
+impl SignedMillisecond {
+    /// Creates a SignedMillisecond instance using the provided sign and magnitude.
+    /// The sign is dropped when the magnitude is zero.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, SignedMillisecond};
+    /// let ms = SignedMillisecond::new(true, Millisecond::ZERO);
+    /// assert!(!ms.is_negative());
+    /// ```
+    pub fn new(negative: bool, magnitude: Millisecond) -> Self {
+        Self {
+            negative: negative && magnitude.as_nanos() > 0,
+            magnitude,
+        }
+    }
+
+    /// Creates a SignedMillisecond instance using the provided nanoseconds.
+    ///
+    /// Out-of-range input, i.e. more than `u64::MAX` years either way, silently saturates
+    /// to a `Millisecond::MAX` magnitude with the same sign, as `Millisecond::from_nanos`
+    /// does; use `try_from_nanos_i128` when that must not go unnoticed.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, SignedMillisecond};
+    /// let ms = SignedMillisecond::from_nanos_i128(-1_800);
+    /// assert_eq!(ms, SignedMillisecond::new(true, Millisecond::from_nanos(1_800)));
+    ///
+    /// let ms = SignedMillisecond::from_nanos_i128(i128::MIN);
+    /// assert_eq!(ms, SignedMillisecond::new(true, Millisecond::MAX));
+    /// ```
+    pub fn from_nanos_i128(nanos: i128) -> Self {
+        Self::new(nanos < 0, Millisecond::from_nanos(nanos.unsigned_abs()))
+    }
+
+    /// Creates a SignedMillisecond instance using the provided nanoseconds, returning
+    /// `Error::Overflow` when the number of years does not fit into a `u64`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Error, SignedMillisecond};
+    /// let ms = SignedMillisecond::try_from_nanos_i128(i128::MIN);
+    /// assert_eq!(ms, Err(Error::Overflow));
+    /// ```
+    pub fn try_from_nanos_i128(nanos: i128) -> Result<Self, Error> {
+        Millisecond::try_from_nanos(nanos.unsigned_abs()).map(|x| Self::new(nanos < 0, x))
+    }
+
+    /// Creates a SignedMillisecond instance using the provided milliseconds.
+    ///
+    /// Out-of-range input, i.e. more than `u64::MAX` years either way, silently saturates
+    /// to a `Millisecond::MAX` magnitude with the same sign, as `Millisecond::from_millis`
+    /// does; use `try_from_millis_i128` when that must not go unnoticed.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, SignedMillisecond};
+    /// let ms = SignedMillisecond::from_millis_i128(-1_800);
+    /// assert_eq!(ms, SignedMillisecond::new(true, Millisecond::from_millis(1_800)));
+    ///
+    /// let ms = SignedMillisecond::from_millis_i128(i128::MIN);
+    /// assert_eq!(ms, SignedMillisecond::new(true, Millisecond::MAX));
+    /// ```
+    pub fn from_millis_i128(millis: i128) -> Self {
+        Self::new(millis < 0, Millisecond::from_millis(millis.unsigned_abs()))
+    }
+
+    /// Creates a SignedMillisecond instance using the provided milliseconds, returning
+    /// `Error::Overflow` when the number of years does not fit into a `u64`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Error, SignedMillisecond};
+    /// let ms = SignedMillisecond::try_from_millis_i128(i128::MIN);
+    /// assert_eq!(ms, Err(Error::Overflow));
+    /// ```
+    pub fn try_from_millis_i128(millis: i128) -> Result<Self, Error> {
+        Millisecond::try_from_millis(millis.unsigned_abs()).map(|x| Self::new(millis < 0, x))
+    }
+
+    /// Returns `true` when the value is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the absolute value as a `Millisecond`.
+    pub fn magnitude(&self) -> Millisecond {
+        self.magnitude
+    }
+
+    /// Returns the total number of whole nanoseconds, including the sign.
+    /// ### example
+    /// ```rust
+    /// use millisecond::SignedMillisecond;
+    /// let ms = SignedMillisecond::from_nanos_i128(-1_800);
+    /// assert_eq!(ms.as_nanos(), -1_800);
+    /// ```
+    pub fn as_nanos(&self) -> i128 {
+        // even a denormalized magnitude is below 2^120 nanoseconds, as `u64::MAX` years are
+        // about 5.8e35 and the other fields add far less, so it fits into an `i128`
+        let nanos = self.magnitude.as_nanos() as i128;
+        if self.negative {
+            -nanos
+        } else {
+            nanos
+        }
+    }
+
+    pub fn to_short_string(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+    pub fn to_long_string(&self) -> String {
+        self.format_with(&FormatOptions::new().verbose(true))
+    }
+    /// Formats the instance according to `options`, prefixing negative values with `-`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{FormatOptions, SignedMillisecond};
+    /// let ms = SignedMillisecond::from_millis_i128(-3_900_000);
+    /// assert_eq!(ms.format_with(&FormatOptions::new().compact(true)), "-1h");
+    /// ```
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.with_sign(self.magnitude.format_with(options))
+    }
+    /// Formats the instance in the short form of `locale`, prefixing negative values with `-`.
+    pub fn format_short_in<L: Locale + ?Sized>(&self, locale: &L) -> String {
+        self.with_sign(self.magnitude.format_short_in(locale))
+    }
+    /// Formats the instance in the long form of `locale`, prefixing negative values with `-`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Language, SignedMillisecond};
+    /// let ms = SignedMillisecond::from_millis_i128(-3_900_000);
+    /// assert_eq!(ms.format_long_in(&Language::German), "-1 Stunde 5 Minuten");
+    /// ```
+    pub fn format_long_in<L: Locale + ?Sized>(&self, locale: &L) -> String {
+        self.with_sign(self.magnitude.format_long_in(locale))
+    }
+    /// Formats the instance according to `options` in the words of `locale`, prefixing
+    /// negative values with `-`.
+    pub fn format_with_in<L: Locale + ?Sized>(
+        &self,
+        options: &FormatOptions,
+        locale: &L,
+    ) -> String {
+        self.with_sign(self.magnitude.format_with_in(options, locale))
+    }
+
+    fn with_sign(&self, text: String) -> String {
+        if self.negative {
+            format!("-{text}")
+        } else {
+            text
+        }
+    }
+}
+
+impl From<Millisecond> for SignedMillisecond {
+    fn from(value: Millisecond) -> Self {
+        Self::new(false, value)
+    }
+}
+
+impl TryFrom<SignedMillisecond> for Millisecond {
+    type Error = Error;
+
+    fn try_from(value: SignedMillisecond) -> Result<Self, Self::Error> {
+        if value.negative {
+            Err(Error::Negative)
+        } else {
+            Ok(value.magnitude)
+        }
+    }
+}
+
+impl Neg for SignedMillisecond {
+    type Output = SignedMillisecond;
+
+    fn neg(self) -> SignedMillisecond {
+        Self::new(!self.negative, self.magnitude)
+    }
+}
+
+impl Ord for SignedMillisecond {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for SignedMillisecond {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for SignedMillisecond {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.to_short_string())
+    }
+}
+
+unsafe impl Sync for SignedMillisecond {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn should_split_signed_values() {
+        let x = SignedMillisecond::from_nanos_i128(-61_000_000_001);
+        assert!(x.is_negative());
+        assert_eq!(x.magnitude(), Millisecond::from_nanos(61_000_000_001));
+        assert_eq!(x.as_nanos(), -61_000_000_001);
+
+        let x = SignedMillisecond::from_millis_i128(61_000);
+        assert!(!x.is_negative());
+        assert_eq!(x.magnitude(), Millisecond::from_secs(61));
+
+        let x = SignedMillisecond::from_nanos_i128(i128::MAX / 100_000);
+        assert_eq!(x.as_nanos(), i128::MAX / 100_000);
+    }
+    #[test]
+    fn should_never_be_negative_zero() {
+        assert_eq!(
+            SignedMillisecond::from_millis_i128(-0),
+            SignedMillisecond::default()
+        );
+        assert!(!(-SignedMillisecond::default()).is_negative());
+    }
+    #[test]
+    fn should_saturate_on_overflow() {
+        let max = SignedMillisecond::new(false, Millisecond::MAX);
+        let min = SignedMillisecond::new(true, Millisecond::MAX);
+        assert_eq!(SignedMillisecond::from_nanos_i128(i128::MAX), max);
+        assert_eq!(SignedMillisecond::from_nanos_i128(i128::MIN), min);
+        assert_eq!(SignedMillisecond::from_millis_i128(i128::MAX), max);
+        assert_eq!(SignedMillisecond::from_millis_i128(i128::MIN), min);
+        assert_eq!(
+            SignedMillisecond::try_from_millis_i128(i128::MAX),
+            Err(Error::Overflow)
+        );
+    }
+    #[test]
+    fn should_format_with_sign() {
+        let x = SignedMillisecond::from_millis_i128(-3_900_000);
+        assert_eq!(x.to_string(), "-1h 5m");
+        assert_eq!(x.to_long_string(), "-1 hour 5 minutes");
+        assert_eq!((-x).to_string(), "1h 5m");
+        assert_eq!((-x).to_long_string(), "1 hour 5 minutes");
+    }
+    #[test]
+    fn should_order_signed_values() {
+        let mut x = [
+            SignedMillisecond::from_millis_i128(5),
+            SignedMillisecond::from_millis_i128(-5),
+            SignedMillisecond::from_millis_i128(0),
+            SignedMillisecond::from_millis_i128(-10),
+        ];
+        x.sort();
+        assert_eq!(x.map(|x| x.as_nanos() / 1_000_000), [-10, -5, 0, 5]);
+    }
+    #[test]
+    fn should_convert_to_unsigned() {
+        let x = SignedMillisecond::from_millis_i128(5);
+        assert_eq!(Millisecond::try_from(x), Ok(Millisecond::from_millis(5)));
+        assert_eq!(Millisecond::try_from(-x), Err(Error::Negative));
+    }
+}
@@ -1,8 +1,14 @@
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::{Display, Formatter};
+use core::time::Duration;
 
+use crate::error::Error;
 use crate::formatter::MillisecondPart;
+use crate::locale::{Language, Locale};
+use crate::options::FormatOptions;
+use crate::unit::Unit;
 
 /// The input value, specified in milliseconds, is parsed and decomposed into constituent
 /// components such as years, days, and seconds. These components can subsequently be utilized
@@ -31,7 +37,7 @@ use crate::formatter::MillisecondPart;
 ///  nanos: 0,
 /// });
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Millisecond {
     pub years: u64,
     pub days: u16,
@@ -46,10 +52,113 @@ pub struct Millisecond {
 unsafe impl Sync for Millisecond {}
 
 impl Millisecond {
+    /// A zero-length duration.
+    pub const ZERO: Millisecond = Millisecond::from_years(0);
+
+    /// The largest duration a normalized instance can hold.
+    pub const MAX: Millisecond = Millisecond {
+        years: u64::MAX,
+        days: 364,
+        hours: 23,
+        minutes: 59,
+        seconds: 59,
+        millis: 999,
+        micros: 999,
+        nanos: 999,
+    };
+
+    /// Creates a Millisecond instance from its components, carrying any overflowing
+    /// component upward, e.g. 90 seconds become 1 minute and 30 seconds.
+    ///
+    /// # Panics
+    /// Panics when the carried number of years does not fit into a `u64`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::new(0, 0, 0, 0, 90, 2_500, 0, 0);
+    /// assert_eq!(ms, Millisecond {
+    ///   years: 0,
+    ///   days: 0,
+    ///   hours: 0,
+    ///   minutes: 1,
+    ///   seconds: 32,
+    ///   millis: 500,
+    ///   micros: 0,
+    ///   nanos: 0,
+    /// })
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        years: u64,
+        days: u16,
+        hours: u8,
+        minutes: u8,
+        seconds: u8,
+        millis: u16,
+        micros: u16,
+        nanos: u16,
+    ) -> Self {
+        Self {
+            years,
+            days,
+            hours,
+            minutes,
+            seconds,
+            millis,
+            micros,
+            nanos,
+        }
+        .normalize()
+    }
+
+    /// Creates a Millisecond instance from its components, returning
+    /// `Error::ComponentOutOfRange` when any of them is outside of its range.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Error, Millisecond};
+    /// let ms = Millisecond::try_from_parts(1, 17, 5, 10, 48, 0, 0, 0);
+    /// assert_eq!(ms, Ok(Millisecond::from_millis(33023448000)));
+    ///
+    /// let ms = Millisecond::try_from_parts(0, 0, 0, 0, 90, 0, 0, 0);
+    /// assert_eq!(ms, Err(Error::ComponentOutOfRange));
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_from_parts(
+        years: u64,
+        days: u16,
+        hours: u8,
+        minutes: u8,
+        seconds: u8,
+        millis: u16,
+        micros: u16,
+        nanos: u16,
+    ) -> Result<Self, Error> {
+        let ms = Self {
+            years,
+            days,
+            hours,
+            minutes,
+            seconds,
+            millis,
+            micros,
+            nanos,
+        };
+        if ms.is_normalized() {
+            Ok(ms)
+        } else {
+            Err(Error::ComponentOutOfRange)
+        }
+    }
+
     /// Creates a Millisecond instance using the provided nanoseconds.
+    ///
+    /// Out-of-range input, i.e. more than `u64::MAX` years, silently saturates to
+    /// `Millisecond::MAX`; use `try_from_nanos` when that must not go unnoticed.
     /// ### example
     /// ```rust
     /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::from_nanos(u128::MAX), Millisecond::MAX);
+    ///
     /// let ms = Millisecond::from_nanos(1_800);
     /// assert_eq!(ms, Millisecond {
     ///   years: 0,
@@ -63,16 +172,36 @@ impl Millisecond {
     /// })
     /// ```
     pub fn from_nanos(nanos: u128) -> Self {
-        Self {
+        Self::try_from_nanos(nanos).unwrap_or(Millisecond::MAX)
+    }
+
+    /// Creates a Millisecond instance using the provided nanoseconds, returning
+    /// `Error::Overflow` when the number of years does not fit into a `u64`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Error, Millisecond};
+    /// let ms = Millisecond::try_from_nanos(1_800);
+    /// assert_eq!(ms, Ok(Millisecond::from_nanos(1_800)));
+    ///
+    /// let ms = Millisecond::try_from_nanos(u128::MAX);
+    /// assert_eq!(ms, Err(Error::Overflow));
+    /// ```
+    pub fn try_from_nanos(nanos: u128) -> Result<Self, Error> {
+        Ok(Self {
             nanos: (nanos % 1000) as u16,
-            ..Self::from_micros(nanos / 1000)
-        }
+            ..Self::try_from_micros(nanos / 1000)?
+        })
     }
 
     /// Creates a Millisecond instance using the provided microseconds.
+    ///
+    /// Out-of-range input, i.e. more than `u64::MAX` years, silently saturates to
+    /// `Millisecond::MAX`; use `try_from_micros` when that must not go unnoticed.
     /// ### example
     /// ```rust
     /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::from_micros(u128::MAX), Millisecond::MAX);
+    ///
     /// let ms = Millisecond::from_micros(1_800);
     /// assert_eq!(ms, Millisecond {
     ///   years: 0,
@@ -86,16 +215,36 @@ impl Millisecond {
     /// })
     /// ```
     pub fn from_micros(micros: u128) -> Self {
-        Self {
+        Self::try_from_micros(micros).unwrap_or(Millisecond::MAX)
+    }
+
+    /// Creates a Millisecond instance using the provided microseconds, returning
+    /// `Error::Overflow` when the number of years does not fit into a `u64`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Error, Millisecond};
+    /// let ms = Millisecond::try_from_micros(1_800);
+    /// assert_eq!(ms, Ok(Millisecond::from_micros(1_800)));
+    ///
+    /// let ms = Millisecond::try_from_micros(u128::MAX);
+    /// assert_eq!(ms, Err(Error::Overflow));
+    /// ```
+    pub fn try_from_micros(micros: u128) -> Result<Self, Error> {
+        Ok(Self {
             micros: (micros % 1000) as u16,
-            ..Self::from_millis(micros / 1000)
-        }
+            ..Self::try_from_millis(micros / 1000)?
+        })
     }
 
     /// Creates a Millisecond instance using the provided milliseconds.
+    ///
+    /// Out-of-range input, i.e. more than `u64::MAX` years, silently saturates to
+    /// `Millisecond::MAX`; use `try_from_millis` when that must not go unnoticed.
     /// ### example
     /// ```rust
     /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::from_millis(u128::MAX), Millisecond::MAX);
+    ///
     /// let ms = Millisecond::from_millis(1_800);
     /// assert_eq!(ms, Millisecond {
     ///   years: 0,
@@ -109,10 +258,28 @@ impl Millisecond {
     /// })
     /// ```
     pub fn from_millis(millis: u128) -> Self {
-        Self {
+        Self::try_from_millis(millis).unwrap_or(Millisecond::MAX)
+    }
+
+    /// Creates a Millisecond instance using the provided milliseconds, returning
+    /// `Error::Overflow` when the number of years does not fit into a `u64`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Error, Millisecond};
+    /// let ms = Millisecond::try_from_millis(1_800);
+    /// assert_eq!(ms, Ok(Millisecond::from_millis(1_800)));
+    ///
+    /// let ms = Millisecond::try_from_millis(u128::MAX);
+    /// assert_eq!(ms, Err(Error::Overflow));
+    /// ```
+    pub fn try_from_millis(millis: u128) -> Result<Self, Error> {
+        const SECS_PER_YEAR: u128 = 365 * 24 * 60 * 60;
+        let secs = millis / 1000;
+        Ok(Self {
+            years: u64::try_from(secs / SECS_PER_YEAR).map_err(|_| Error::Overflow)?,
             millis: (millis % 1000) as u16,
-            ..Self::from_secs((millis / 1000) as u64)
-        }
+            ..Self::from_secs((secs % SECS_PER_YEAR) as u64)
+        })
     }
 
     /// Creates a Millisecond instance using the provided seconds.
@@ -223,7 +390,7 @@ impl Millisecond {
     ///   nanos: 0,
     /// })
     /// ```
-    pub fn from_years(years: u64) -> Self {
+    pub const fn from_years(years: u64) -> Self {
         Self {
             years,
             days: 0,
@@ -235,24 +402,302 @@ impl Millisecond {
             nanos: 0,
         }
     }
+
+    /// Checks whether every component is within its range, i.e. the instance is
+    /// the same as the one created by `from_nanos` for its total.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// assert!(Millisecond::from_secs(90).is_normalized());
+    /// assert!(!Millisecond { seconds: 90, ..Default::default() }.is_normalized());
+    /// ```
+    pub fn is_normalized(&self) -> bool {
+        self.days < 365
+            && self.hours < 24
+            && self.minutes < 60
+            && self.seconds < 60
+            && self.millis < 1_000
+            && self.micros < 1_000
+            && self.nanos < 1_000
+    }
+
+    /// Carries every overflowing component upward, e.g. 2500 millis become
+    /// 2 seconds and 500 millis.
+    ///
+    /// # Panics
+    /// Panics when the carried number of years does not fit into a `u64`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond { seconds: 90, millis: 2_500, ..Default::default() };
+    /// assert_eq!(ms.normalize(), Millisecond::from_millis(92_500));
+    /// ```
+    pub fn normalize(&self) -> Self {
+        self.checked_normalize()
+            .expect("overflow when normalizing Millisecond")
+    }
+
+    /// Carries every overflowing component upward, returning `None` when the
+    /// carried number of years does not fit into a `u64`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond { years: u64::MAX, days: 365, ..Default::default() };
+    /// assert_eq!(ms.checked_normalize(), None);
+    /// ```
+    pub fn checked_normalize(&self) -> Option<Self> {
+        if self.is_normalized() {
+            Some(*self)
+        } else {
+            Self::try_from_nanos(self.as_nanos()).ok()
+        }
+    }
 }
 
 impl Millisecond {
+    /// Returns the total number of whole nanoseconds, summing every field of the instance.
+    /// A year is counted as 365 days, the same as in `from_days`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_nanos(61_000_000_123);
+    /// assert_eq!(ms.as_nanos(), 61_000_000_123);
+    /// ```
+    pub fn as_nanos(&self) -> u128 {
+        (self.as_secs_only() * 1_000 + self.millis as u128) * 1_000_000
+            + self.micros as u128 * 1_000
+            + self.nanos as u128
+    }
+
+    /// Returns the total number of whole microseconds, summing every field of the instance.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_nanos(61_000_001_999);
+    /// assert_eq!(ms.as_micros(), 61_000_001);
+    /// ```
+    pub fn as_micros(&self) -> u128 {
+        self.as_nanos() / 1_000
+    }
+
+    /// Returns the total number of whole milliseconds, summing every field of the instance.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_millis(33023448000);
+    /// assert_eq!(ms.as_millis(), 33023448000);
+    /// ```
+    pub fn as_millis(&self) -> u128 {
+        self.as_nanos() / 1_000_000
+    }
+
+    /// Returns the total number of whole seconds, summing every field of the instance.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_millis(61_999);
+    /// assert_eq!(ms.as_secs(), 61);
+    /// ```
+    pub fn as_secs(&self) -> u128 {
+        self.as_nanos() / 1_000_000_000
+    }
+
+    /// Returns the total number of seconds as `f64`, including the fractional part.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_millis(1_500);
+    /// assert_eq!(ms.as_secs_f64(), 1.5);
+    /// ```
+    pub fn as_secs_f64(&self) -> f64 {
+        let nanos = self.as_nanos();
+        (nanos / 1_000_000_000) as f64 + (nanos % 1_000_000_000) as f64 / 1_000_000_000.0
+    }
+
+    /// Sums the fields from `years` down to `seconds` into seconds.
+    fn as_secs_only(&self) -> u128 {
+        let days = self.years as u128 * 365 + self.days as u128;
+        let hours = days * 24 + self.hours as u128;
+        let minutes = hours * 60 + self.minutes as u128;
+        minutes * 60 + self.seconds as u128
+    }
+
+    /// Rebuilds a `Duration` from every field of the instance.
+    /// Returns `Error::Overflow` when the total number of seconds does not fit into a `u64`.
+    /// ### example
+    /// ```rust
+    /// use core::time::Duration;
+    /// use millisecond::{Error, Millisecond};
+    ///
+    /// let ms = Millisecond::from_millis(1_800);
+    /// assert_eq!(ms.to_duration(), Ok(Duration::from_millis(1_800)));
+    ///
+    /// let ms = Millisecond::from_years(u64::MAX);
+    /// assert_eq!(ms.to_duration(), Err(Error::Overflow));
+    /// ```
+    pub fn to_duration(&self) -> Result<Duration, Error> {
+        let nanos = self.as_nanos();
+        u64::try_from(nanos / 1_000_000_000)
+            .map(|secs| Duration::new(secs, (nanos % 1_000_000_000) as u32))
+            .map_err(|_| Error::Overflow)
+    }
+}
+
+impl From<Duration> for Millisecond {
+    fn from(value: Duration) -> Self {
+        let nanos = value.subsec_nanos();
+        Self {
+            millis: (nanos / 1_000_000) as u16,
+            micros: (nanos / 1_000 % 1_000) as u16,
+            nanos: (nanos % 1_000) as u16,
+            ..Self::from_secs(value.as_secs())
+        }
+    }
+}
+
+impl TryFrom<Millisecond> for Duration {
+    type Error = Error;
+
+    fn try_from(value: Millisecond) -> Result<Self, Self::Error> {
+        value.to_duration()
+    }
+}
+
+/// Instances are ordered by their total duration. Instances with the same total but
+/// different fields (e.g. a denormalized one) are then ordered field by field, from
+/// `years` down to `nanos`, so the ordering stays consistent with `Eq`.
+impl Ord for Millisecond {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_nanos()
+            .cmp(&other.as_nanos())
+            .then_with(|| self.fields().cmp(&other.fields()))
+    }
+}
+
+impl PartialOrd for Millisecond {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Millisecond {
+    fn fields(&self) -> (u64, u16, u8, u8, u8, u16, u16, u16) {
+        (
+            self.years,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.millis,
+            self.micros,
+            self.nanos,
+        )
+    }
+
     pub fn to_short_string(&self) -> String {
-        MillisecondPart::from_millisecond(self)
-            .iter()
-            .map(|x| x.to_short_string())
-            .collect::<Vec<String>>()
-            .join(" ")
+        self.format_with(&FormatOptions::default())
     }
     pub fn to_long_string(&self) -> String {
-        MillisecondPart::from_millisecond(self)
+        self.format_with(&FormatOptions::new().verbose(true))
+    }
+    /// Formats the instance in the short form, rendering a zero-length value in `unit`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, Unit};
+    /// assert_eq!(Millisecond::ZERO.to_short_string(), "0ms");
+    /// assert_eq!(Millisecond::ZERO.to_short_string_with_zero_unit(Unit::Seconds), "0s");
+    /// ```
+    pub fn to_short_string_with_zero_unit(&self, unit: Unit) -> String {
+        self.format_with(&FormatOptions::new().zero_unit(unit))
+    }
+    /// Formats the instance in the long form, rendering a zero-length value in `unit`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Millisecond, Unit};
+    /// assert_eq!(Millisecond::ZERO.to_long_string(), "0 milliseconds");
+    /// assert_eq!(Millisecond::ZERO.to_long_string_with_zero_unit(Unit::Seconds), "0 seconds");
+    /// ```
+    pub fn to_long_string_with_zero_unit(&self, unit: Unit) -> String {
+        self.format_with(&FormatOptions::new().verbose(true).zero_unit(unit))
+    }
+    /// Formats the instance in the short form, showing at most `count` of the most
+    /// significant units. The dropped remainder is rounded into the last shown unit.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_millis(33023448000);
+    /// assert_eq!(ms.to_short_string_with_units(2), "1y 17d");
+    ///
+    /// let ms = Millisecond::from_millis(3_599_900);
+    /// assert_eq!(ms.to_short_string_with_units(1), "1h");
+    /// ```
+    pub fn to_short_string_with_units(&self, count: usize) -> String {
+        self.format_with(&FormatOptions::new().unit_count(count))
+    }
+    /// Formats the instance in the long form, showing at most `count` of the most
+    /// significant units. The dropped remainder is rounded into the last shown unit.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_millis(33023448000);
+    /// assert_eq!(ms.to_long_string_with_units(3), "1 year 17 days 5 hours");
+    /// ```
+    pub fn to_long_string_with_units(&self, count: usize) -> String {
+        self.format_with(&FormatOptions::new().verbose(true).unit_count(count))
+    }
+    /// Formats the instance according to `options`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{FormatOptions, Millisecond};
+    /// let ms = Millisecond::from_millis(33023448250);
+    /// let opts = FormatOptions::new().separate_millis(true);
+    /// assert_eq!(ms.format_with(&opts), "1y 17d 5h 10m 48s 250ms");
+    /// ```
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.format_with_in(options, &Language::English)
+    }
+    /// Formats the instance in the short form of `locale`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Language, Millisecond};
+    /// let ms = Millisecond::from_millis(33023448000);
+    /// assert_eq!(ms.format_short_in(&Language::French), "1 a 17 j 5 h 10 min 48 s");
+    /// ```
+    pub fn format_short_in<L: Locale + ?Sized>(&self, locale: &L) -> String {
+        self.format_with_in(&FormatOptions::default(), locale)
+    }
+    /// Formats the instance in the long form of `locale`, e.g. with its unit names and
+    /// plural forms.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{Language, Millisecond};
+    /// let ms = Millisecond::from_millis(33023448000);
+    /// assert_eq!(ms.format_long_in(&Language::Russian), "1 год 17 дней 5 часов 10 минут 48 секунд");
+    /// ```
+    pub fn format_long_in<L: Locale + ?Sized>(&self, locale: &L) -> String {
+        self.format_with_in(&FormatOptions::new().verbose(true), locale)
+    }
+    /// Formats the instance according to `options` in the words of `locale`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{FormatOptions, Language, Millisecond};
+    /// let ms = Millisecond::from_millis(33023448000);
+    /// let opts = FormatOptions::new().verbose(true).unit_count(2);
+    /// assert_eq!(ms.format_with_in(&opts, &Language::Spanish), "1 año 17 días");
+    /// ```
+    pub fn format_with_in<L: Locale + ?Sized>(
+        &self,
+        options: &FormatOptions,
+        locale: &L,
+    ) -> String {
+        MillisecondPart::from_millisecond_with_options(self, options)
             .iter()
-            .map(|x| x.to_long_string())
+            .map(|x| x.format_with_in(options, locale))
             .collect::<Vec<String>>()
-            .join(" ")
+            .join(locale.separator())
     }
 }
+
 impl Display for Millisecond {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.to_short_string())
@@ -261,6 +706,7 @@ impl Display for Millisecond {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn should_split_from_millis_basic() {
@@ -523,14 +969,14 @@ mod tests {
                 },
             ),
             (
-                u128::MAX,
+                u64::MAX as u128 * 365 * 24 * 60 * 60 * 1000 + 999,
                 Millisecond {
-                    years: 360324529090,
-                    days: 264,
-                    hours: 9,
-                    minutes: 29,
-                    seconds: 55,
-                    millis: 455,
+                    years: u64::MAX,
+                    days: 0,
+                    hours: 0,
+                    minutes: 0,
+                    seconds: 0,
+                    millis: 999,
                     micros: 0,
                     nanos: 0,
                 },
@@ -737,4 +1183,256 @@ mod tests {
             }
         );
     }
+    #[test]
+    fn should_convert_from_duration() {
+        let x = Millisecond::from(Duration::new(33023448, 123_456_789));
+        assert_eq!(
+            x,
+            Millisecond {
+                years: 1,
+                days: 17,
+                hours: 5,
+                minutes: 10,
+                seconds: 48,
+                millis: 123,
+                micros: 456,
+                nanos: 789,
+            }
+        );
+        assert_eq!(Millisecond::from(Duration::ZERO), Millisecond::default());
+    }
+    #[test]
+    fn should_convert_to_duration() {
+        let cases = [
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            Duration::new(33023448, 123_456_789),
+            Duration::MAX,
+        ];
+        for d in cases {
+            assert_eq!(Millisecond::from(d).to_duration(), Ok(d), "{d:?}");
+            assert_eq!(Duration::try_from(Millisecond::from(d)), Ok(d), "{d:?}");
+        }
+
+        let x = Millisecond {
+            seconds: 90,
+            millis: 2500,
+            ..Default::default()
+        };
+        assert_eq!(x.to_duration(), Ok(Duration::from_millis(92_500)));
+    }
+    #[test]
+    fn should_report_duration_overflow() {
+        let x = Millisecond::from(Duration::MAX);
+        let x = Millisecond {
+            nanos: x.nanos + 1,
+            ..x
+        };
+        assert_eq!(x.to_duration(), Err(Error::Overflow));
+        assert_eq!(
+            Duration::try_from(Millisecond::from_years(u64::MAX)),
+            Err(Error::Overflow)
+        );
+    }
+    #[test]
+    fn should_recompose_totals() {
+        let x = Millisecond {
+            years: 1,
+            days: 17,
+            hours: 5,
+            minutes: 10,
+            seconds: 48,
+            millis: 123,
+            micros: 456,
+            nanos: 789,
+        };
+        assert_eq!(x.as_nanos(), 33_023_448_123_456_789);
+        assert_eq!(x.as_micros(), 33_023_448_123_456);
+        assert_eq!(x.as_millis(), 33_023_448_123);
+        assert_eq!(x.as_secs(), 33023448);
+        assert_eq!(x.as_secs_f64(), 33023448.123456789);
+
+        let x = Millisecond {
+            seconds: 90,
+            millis: 2500,
+            ..Default::default()
+        };
+        assert_eq!(x.as_millis(), 92_500);
+        assert_eq!(Millisecond::default().as_nanos(), 0);
+    }
+    #[test]
+    fn should_recompose_what_was_split() {
+        let cases = [0, 1, 999, 1_000, 119_999, 33023448000, 9007199254740991];
+        for k in cases {
+            assert_eq!(
+                Millisecond::from_millis(k).as_millis(),
+                k,
+                "as_millis ({k})"
+            );
+            assert_eq!(
+                Millisecond::from_micros(k).as_micros(),
+                k,
+                "as_micros ({k})"
+            );
+            assert_eq!(Millisecond::from_nanos(k).as_nanos(), k, "as_nanos ({k})");
+        }
+
+        let x = Millisecond::from_years(u64::MAX);
+        assert_eq!(x.as_secs(), u64::MAX as u128 * 365 * 24 * 60 * 60);
+    }
+    #[test]
+    fn should_order_by_total_duration() {
+        let mut x = [
+            Millisecond::from_years(1),
+            Millisecond::from_nanos(1),
+            Millisecond::from_days(364),
+            Millisecond::ZERO,
+            Millisecond::from_secs(61),
+        ];
+        x.sort();
+        assert_eq!(
+            x,
+            [
+                Millisecond::ZERO,
+                Millisecond::from_nanos(1),
+                Millisecond::from_secs(61),
+                Millisecond::from_days(364),
+                Millisecond::from_years(1),
+            ]
+        );
+        assert_eq!(x.iter().max(), Some(&Millisecond::from_years(1)));
+    }
+    #[test]
+    fn should_order_denormalized_consistently_with_eq() {
+        let normalized = Millisecond::from_secs(90);
+        let denormalized = Millisecond {
+            seconds: 90,
+            ..Default::default()
+        };
+        assert_ne!(normalized, denormalized);
+        assert_ne!(normalized.cmp(&denormalized), Ordering::Equal);
+        assert_eq!(
+            normalized.cmp(&denormalized),
+            denormalized.cmp(&normalized).reverse()
+        );
+
+        let bigger = Millisecond {
+            seconds: 91,
+            ..Default::default()
+        };
+        assert!(normalized < bigger);
+        assert!(Millisecond::from_minutes(2) > bigger);
+    }
+    #[test]
+    fn should_normalize() {
+        let x = Millisecond {
+            years: 0,
+            days: 400,
+            hours: 30,
+            minutes: 61,
+            seconds: 90,
+            millis: 2500,
+            micros: 1001,
+            nanos: 1002,
+        };
+        assert!(!x.is_normalized());
+        let y = x.normalize();
+        assert!(y.is_normalized());
+        assert_eq!(y.as_nanos(), x.as_nanos());
+        assert_eq!(
+            y,
+            Millisecond {
+                years: 1,
+                days: 36,
+                hours: 7,
+                minutes: 2,
+                seconds: 32,
+                millis: 501,
+                micros: 2,
+                nanos: 2,
+            }
+        );
+        assert_eq!(Millisecond::MAX.normalize(), Millisecond::MAX);
+    }
+    #[test]
+    fn should_validate_parts() {
+        assert_eq!(
+            Millisecond::try_from_parts(1, 364, 23, 59, 59, 999, 999, 999),
+            Ok(Millisecond {
+                years: 1,
+                days: 364,
+                hours: 23,
+                minutes: 59,
+                seconds: 59,
+                millis: 999,
+                micros: 999,
+                nanos: 999,
+            })
+        );
+        let cases = [
+            (0, 365, 0, 0, 0, 0, 0, 0),
+            (0, 0, 24, 0, 0, 0, 0, 0),
+            (0, 0, 0, 60, 0, 0, 0, 0),
+            (0, 0, 0, 0, 60, 0, 0, 0),
+            (0, 0, 0, 0, 0, 1000, 0, 0),
+            (0, 0, 0, 0, 0, 0, 1000, 0),
+            (0, 0, 0, 0, 0, 0, 0, 1000),
+        ];
+        for (y, d, h, m, s, ms, us, ns) in cases {
+            assert_eq!(
+                Millisecond::try_from_parts(y, d, h, m, s, ms, us, ns),
+                Err(Error::ComponentOutOfRange)
+            );
+            assert!(Millisecond::new(y, d, h, m, s, ms, us, ns).is_normalized());
+        }
+    }
+    #[test]
+    fn should_format_denormalized_as_normalized() {
+        let x = Millisecond {
+            seconds: 90,
+            millis: 2500,
+            ..Default::default()
+        };
+        assert_eq!(x.to_string(), Millisecond::from_millis(92_500).to_string());
+        assert_eq!(
+            x.to_long_string(),
+            Millisecond::from_millis(92_500).to_long_string()
+        );
+    }
+    #[test]
+    fn should_report_split_overflow() {
+        let max = Millisecond::MAX.as_nanos();
+        assert_eq!(Millisecond::try_from_nanos(max), Ok(Millisecond::MAX));
+        assert_eq!(Millisecond::try_from_nanos(max + 1), Err(Error::Overflow));
+        assert_eq!(
+            Millisecond::try_from_micros(max / 1_000 + 1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Millisecond::try_from_millis(max / 1_000_000 + 1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(Millisecond::try_from_nanos(u128::MAX), Err(Error::Overflow));
+        assert_eq!(
+            Millisecond::try_from_micros(u128::MAX),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Millisecond::try_from_millis(u128::MAX),
+            Err(Error::Overflow)
+        );
+    }
+    #[test]
+    fn should_saturate_on_split_overflow() {
+        assert_eq!(Millisecond::from_nanos(u128::MAX), Millisecond::MAX);
+        assert_eq!(Millisecond::from_micros(u128::MAX), Millisecond::MAX);
+        assert_eq!(Millisecond::from_millis(u128::MAX), Millisecond::MAX);
+        // one nanosecond past the largest duration saturates instead of wrapping
+        let nanos = Millisecond::MAX.as_nanos() + 1;
+        assert_eq!(Millisecond::from_nanos(nanos), Millisecond::MAX);
+        assert_eq!(Millisecond::try_from_nanos(nanos), Err(Error::Overflow));
+        let millis = Millisecond::MAX.as_millis() + 1;
+        assert_eq!(Millisecond::from_millis(millis), Millisecond::MAX);
+        assert_eq!(Millisecond::try_from_millis(millis), Err(Error::Overflow));
+    }
 }
@@ -0,0 +1,285 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parser::{parse_sum, Scanner};
+use crate::{Millisecond, Unit};
+
+/// The units written by `format_timespan` in systemd, in microseconds. A year is
+/// 365.25 days and a month is a twelfth of it, unlike `Millisecond::years`.
+const FORMAT_UNITS: [(&str, u128); 9] = [
+    ("y", 31_557_600_000_000),
+    ("month", 2_629_800_000_000),
+    ("w", 604_800_000_000),
+    ("d", 86_400_000_000),
+    ("h", 3_600_000_000),
+    ("min", 60_000_000),
+    ("s", 1_000_000),
+    ("ms", 1_000),
+    ("us", 1),
+];
+
+/// The units read by `parse_time` in systemd, in microseconds. They are matched as
+/// prefixes in this order, e.g. `ms` before `m`.
+const PARSE_UNITS: [(&str, u128); 30] = [
+    ("seconds", 1_000_000),
+    ("second", 1_000_000),
+    ("sec", 1_000_000),
+    ("s", 1_000_000),
+    ("minutes", 60_000_000),
+    ("minute", 60_000_000),
+    ("min", 60_000_000),
+    ("months", 2_629_800_000_000),
+    ("month", 2_629_800_000_000),
+    ("M", 2_629_800_000_000),
+    ("msec", 1_000),
+    ("ms", 1_000),
+    ("m", 60_000_000),
+    ("hours", 3_600_000_000),
+    ("hour", 3_600_000_000),
+    ("hr", 3_600_000_000),
+    ("h", 3_600_000_000),
+    ("days", 86_400_000_000),
+    ("day", 86_400_000_000),
+    ("d", 86_400_000_000),
+    ("weeks", 604_800_000_000),
+    ("week", 604_800_000_000),
+    ("w", 604_800_000_000),
+    ("years", 31_557_600_000_000),
+    ("year", 31_557_600_000_000),
+    ("y", 31_557_600_000_000),
+    ("usec", 1),
+    ("us", 1),
+    ("μs", 1),
+    ("µs", 1),
+];
+
+impl Millisecond {
+    /// Formats the instance the same as `format_timespan` in systemd, e.g. `1h 30min` or
+    /// `5.300000s`, down to microsecond precision. `Millisecond::MAX` stands for
+    /// systemd's `infinity`, and a zero-length value is formatted as `0`.
+    ///
+    /// systemd uses a year of 365.25 days and a month of 30.44 days, so whole
+    /// `Millisecond::years` are not written as `y`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// assert_eq!(Millisecond::from_minutes(90).to_systemd_timespan(), "1h 30min");
+    /// assert_eq!(Millisecond::from_millis(5_300).to_systemd_timespan(), "5.300000s");
+    /// assert_eq!(Millisecond::from_days(15).to_systemd_timespan(), "2w 1d");
+    /// assert_eq!(Millisecond::MAX.to_systemd_timespan(), "infinity");
+    /// ```
+    pub fn to_systemd_timespan(&self) -> String {
+        self.to_systemd_timespan_with_accuracy(Millisecond::from_micros(1))
+    }
+
+    /// Formats the instance like `to_systemd_timespan`, dropping what is below `accuracy`
+    /// the same as the `accuracy` argument of `format_timespan`, e.g. `1s` in `systemctl`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_millis(65_300);
+    /// assert_eq!(ms.to_systemd_timespan_with_accuracy(Millisecond::from_secs(1)), "1min 5s");
+    /// let ms = Millisecond::from_millis(5_300);
+    /// assert_eq!(ms.to_systemd_timespan_with_accuracy(Millisecond::from_millis(1)), "5.300s");
+    /// ```
+    pub fn to_systemd_timespan_with_accuracy(&self, accuracy: Millisecond) -> String {
+        if self.as_nanos() >= Millisecond::MAX.as_nanos() {
+            return String::from("infinity");
+        }
+        let mut t = self.as_micros();
+        if t == 0 {
+            return String::from("0");
+        }
+        let accuracy = accuracy.as_micros();
+
+        let mut text = String::new();
+        for (suffix, unit) in FORMAT_UNITS {
+            if t == 0 || (t < accuracy && !text.is_empty()) {
+                break;
+            }
+            if t < unit {
+                continue;
+            }
+            let sep = if text.is_empty() { "" } else { " " };
+            let (a, mut b) = (t / unit, t % unit);
+
+            // below a minute, the remainder is written as a fraction of the last unit
+            if t < 60_000_000 && b > 0 {
+                let mut digits = digit_count(unit) as i32;
+                let mut cc = accuracy;
+                while cc > 1 {
+                    b /= 10;
+                    digits -= 1;
+                    cc /= 10;
+                }
+                if digits > 0 {
+                    let width = digits as usize;
+                    text += &format!("{sep}{a}.{b:0width$}{suffix}");
+                    break;
+                }
+            }
+            text += &format!("{sep}{a}{suffix}");
+            t = b;
+        }
+        text
+    }
+
+    /// Parses a time span the same as `parse_time` in systemd, e.g. `1h 30min`, `2weeks`,
+    /// `5s 300ms` or `infinity`, which is read as `Millisecond::MAX`. Every unit alias of
+    /// systemd is accepted, a number without a unit is in seconds, and decimals are
+    /// truncated to microseconds.
+    /// ### example
+    /// ```rust
+    /// use millisecond::Millisecond;
+    /// let ms = Millisecond::from_systemd_timespan("1h 30min").unwrap();
+    /// assert_eq!(ms, Millisecond::from_minutes(90));
+    ///
+    /// let ms = Millisecond::from_systemd_timespan("5s 300ms").unwrap();
+    /// assert_eq!(ms, Millisecond::from_millis(5_300));
+    ///
+    /// let ms = Millisecond::from_systemd_timespan("infinity").unwrap();
+    /// assert_eq!(ms, Millisecond::MAX);
+    /// ```
+    pub fn from_systemd_timespan(input: &str) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        s.skip_whitespace();
+        if s.eat("infinity") {
+            s.skip_whitespace();
+            if !s.is_end() {
+                return Err(ParseError::new(ParseErrorKind::UnexpectedToken, s.pos()));
+            }
+            return Ok(Millisecond::MAX);
+        }
+
+        let nanos = parse_sum(&mut s, |s| {
+            let start = s.pos();
+            if s.eat("-") {
+                return Err(ParseError::new(ParseErrorKind::Negative, start));
+            }
+            let number = s.loose_decimal()?;
+            let end = s.pos();
+            s.skip_whitespace();
+            let micros = match PARSE_UNITS.iter().find(|(name, _)| s.eat(name)) {
+                Some((_, micros)) => *micros,
+                // "12.34.56" is rejected, but "12.34 .56" is two numbers of seconds
+                None if s.pos() == end && !s.is_end() => {
+                    let kind = match s.next_char() {
+                        Some(c) if c.is_alphabetic() => ParseErrorKind::UnknownUnit,
+                        _ => ParseErrorKind::UnexpectedToken,
+                    };
+                    return Err(ParseError::new(kind, end));
+                }
+                None => 1_000_000,
+            };
+            number
+                .scale(micros * Unit::Micros.nanos())
+                .map(|x| x / Unit::Micros.nanos() * Unit::Micros.nanos())
+                .ok_or(ParseError::new(ParseErrorKind::Overflow, start))
+        })?;
+        Ok(Millisecond::from_nanos(nanos))
+    }
+}
+
+/// Returns the number of times `x` can be divided by 10 before reaching 1.
+fn digit_count(mut x: u128) -> u32 {
+    let mut count = 0;
+    while x > 1 {
+        x /= 10;
+        count += 1;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_systemd_timespan() {
+        let cases = [
+            (0, "0"),
+            (1, "1us"),
+            (1_500, "1.500ms"),
+            (5_300_000, "5.300000s"),
+            (59_999_999, "59.999999s"),
+            (60_000_000, "1min"),
+            (61_500_000, "1min 1.500000s"),
+            (5_400_000_000, "1h 30min"),
+            (90_061_000_000, "1d 1h 1min 1s"),
+            (1_296_000_000_000, "2w 1d"),
+            (31_536_000_000_000, "11month 4w 2d 4h 30min"),
+            (31_557_600_000_000, "1y"),
+            (34_187_400_000_000, "1y 1month"),
+        ];
+        for (k, v) in cases {
+            let ms = Millisecond::from_micros(k);
+            assert_eq!(ms.to_systemd_timespan(), v, "{k}");
+            assert_eq!(Millisecond::from_systemd_timespan(v), Ok(ms), "{v}");
+        }
+        assert_eq!(Millisecond::from_nanos(999).to_systemd_timespan(), "0");
+        assert_eq!(Millisecond::MAX.to_systemd_timespan(), "infinity");
+    }
+    #[test]
+    fn should_format_systemd_timespan_with_accuracy() {
+        let sec = Millisecond::from_secs(1);
+        let cases = [
+            (500_000, "500ms"),
+            (5_300_000, "5s"),
+            (61_500_000, "1min 1s"),
+            (90_061_000_000, "1d 1h 1min 1s"),
+        ];
+        for (k, v) in cases {
+            let ms = Millisecond::from_micros(k);
+            assert_eq!(ms.to_systemd_timespan_with_accuracy(sec), v, "{k}");
+        }
+        let ms = Millisecond::from_micros(5_300_000);
+        let accuracy = Millisecond::from_millis(100);
+        assert_eq!(ms.to_systemd_timespan_with_accuracy(accuracy), "5.3s");
+    }
+    #[test]
+    fn should_parse_systemd_aliases() {
+        let cases = [
+            ("5", 5_000_000),
+            ("5 seconds 5second 5sec 5s", 20_000_000),
+            ("2minutes 2minute 2min 2m", 480_000_000),
+            ("1months 1month 1M", 7_889_400_000_000),
+            ("3msec 3ms", 6_000),
+            ("1hours 1hour 1hr 1h", 14_400_000_000),
+            ("1days 1day 1d", 259_200_000_000),
+            ("2weeks", 1_209_600_000_000),
+            ("1week 1w", 1_209_600_000_000),
+            ("1years 1year 1y", 94_672_800_000_000),
+            ("7usec 7us 7μs 7µs", 28),
+            ("1.5min", 90_000_000),
+            ("1h30min", 5_400_000_000),
+            (" 1 h 30 min ", 5_400_000_000),
+            ("0.0000005s", 0),
+            ("12.34 .56", 12_900_000),
+            ("  infinity  ", Millisecond::MAX.as_micros()),
+        ];
+        for (k, v) in cases {
+            let ms = Millisecond::from_systemd_timespan(k).map(|x| x.as_micros());
+            assert_eq!(ms, Ok(v), "{k}");
+        }
+    }
+    #[test]
+    fn should_reject_invalid_systemd_timespan() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("infinityx", ParseErrorKind::UnexpectedToken, 8),
+            ("-5s", ParseErrorKind::Negative, 0),
+            ("5s -5s", ParseErrorKind::Negative, 3),
+            ("5x", ParseErrorKind::UnknownUnit, 1),
+            ("12.34.56", ParseErrorKind::UnexpectedToken, 5),
+            ("1h .", ParseErrorKind::InvalidNumber, 3),
+        ];
+        for (k, kind, offset) in cases {
+            assert_eq!(
+                Millisecond::from_systemd_timespan(k),
+                Err(ParseError::new(kind, offset)),
+                "{k}"
+            );
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use core::fmt::{Display, Formatter};
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parser::Scanner;
+use crate::{Error, Millisecond, SignedMillisecond, Unit};
+
+/// The frame rates of a SMPTE timecode. The NTSC rates of 29.97 and 59.94 fps have a
+/// drop-frame variant, which skips frame numbers to keep the timecode in step with
+/// the clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameRate {
+    Fps24,
+    Fps25,
+    Fps29_97,
+    Fps29_97Drop,
+    Fps30,
+    Fps59_94,
+    Fps59_94Drop,
+}
+
+impl FrameRate {
+    /// Returns the number of frames counted in a second of the timecode, e.g. 30 for
+    /// 29.97 fps.
+    pub fn timebase(&self) -> u8 {
+        match self {
+            FrameRate::Fps24 => 24,
+            FrameRate::Fps25 => 25,
+            FrameRate::Fps29_97 | FrameRate::Fps29_97Drop | FrameRate::Fps30 => 30,
+            FrameRate::Fps59_94 | FrameRate::Fps59_94Drop => 60,
+        }
+    }
+
+    /// Returns `true` for the drop-frame rates, which are written as `HH:MM:SS;FF`.
+    pub fn is_drop_frame(&self) -> bool {
+        self.dropped_frames() > 0
+    }
+
+    /// Returns the exact rate as a fraction of frames per second.
+    fn ratio(&self) -> (u128, u128) {
+        match self {
+            FrameRate::Fps29_97 | FrameRate::Fps29_97Drop => (30_000, 1_001),
+            FrameRate::Fps59_94 | FrameRate::Fps59_94Drop => (60_000, 1_001),
+            _ => (self.timebase() as u128, 1),
+        }
+    }
+
+    /// Returns the frame numbers skipped at the start of each minute, except for
+    /// every tenth one.
+    fn dropped_frames(&self) -> u128 {
+        match self {
+            FrameRate::Fps29_97Drop => 2,
+            FrameRate::Fps59_94Drop => 4,
+            _ => 0,
+        }
+    }
+
+    /// Returns the number of frames in a day of timecode, i.e. up to `23:59:59:FF`.
+    fn frames_per_day(&self) -> u128 {
+        let minutes = 24 * 60;
+        self.timebase() as u128 * 86_400 - self.dropped_frames() * (minutes - minutes / 10)
+    }
+
+    /// Returns the time at which `frame` starts, rounded to the nearest nanosecond.
+    fn frame_nanos(&self, frame: u128) -> u128 {
+        let (num, den) = self.ratio();
+        (2 * frame * den * Unit::Seconds.nanos() + num) / (2 * num)
+    }
+}
+
+/// A SMPTE timecode, i.e. `HH:MM:SS:FF`, or `HH:MM:SS;FF` for a drop-frame rate. It
+/// spans a day, from `00:00:00:00` to `23:59:59:FF`.
+/// ## Example
+/// ```rust
+/// use millisecond::{FrameRate, Millisecond};
+///
+/// let (tc, _) = Millisecond::from_secs(3_600).to_timecode(FrameRate::Fps25).unwrap();
+/// assert_eq!(tc.to_string(), "01:00:00:00");
+/// assert_eq!(tc.hours, 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timecode {
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+    pub frames: u8,
+    pub drop_frame: bool,
+}
+
+impl Timecode {
+    /// Labels the frame at `frame` from the start, skipping the dropped frame numbers.
+    fn from_frame(frame: u128, rate: FrameRate) -> Self {
+        let timebase = rate.timebase() as u128;
+        let drop = rate.dropped_frames();
+        let mut frame = frame;
+        if drop > 0 {
+            let per_minute = timebase * 60 - drop;
+            let per_ten_minutes = timebase * 600 - drop * 9;
+            let tens = frame / per_ten_minutes;
+            let rest = frame % per_ten_minutes;
+            frame += drop * 9 * tens;
+            if rest > drop {
+                frame += drop * ((rest - drop) / per_minute);
+            }
+        }
+        let seconds = frame / timebase;
+        Self {
+            hours: (seconds / 3_600) as u8,
+            minutes: (seconds / 60 % 60) as u8,
+            seconds: (seconds % 60) as u8,
+            frames: (frame % timebase) as u8,
+            drop_frame: drop > 0,
+        }
+    }
+
+    /// Returns the position of the labeled frame from the start.
+    fn frame(&self, rate: FrameRate) -> u128 {
+        let minutes = self.hours as u128 * 60 + self.minutes as u128;
+        let seconds = minutes * 60 + self.seconds as u128;
+        seconds * rate.timebase() as u128 + self.frames as u128
+            - rate.dropped_frames() * (minutes - minutes / 10)
+    }
+}
+
+impl Display for Timecode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let sep = if self.drop_frame { ';' } else { ':' };
+        write!(
+            f,
+            "{:02}:{:02}:{:02}{sep}{:02}",
+            self.hours, self.minutes, self.seconds, self.frames
+        )
+    }
+}
+
+impl Millisecond {
+    /// Converts the instance into the timecode of the nearest frame at `rate`, along with
+    /// the rounding error, i.e. the start of that frame minus the instance, to the nearest
+    /// nanosecond. Halves are rounded up. Returns `Error::Overflow` when the frame is
+    /// beyond `23:59:59:FF`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{FrameRate, Millisecond, SignedMillisecond};
+    /// let ms = Millisecond::from_millis(1_010);
+    /// let (tc, error) = ms.to_timecode(FrameRate::Fps25).unwrap();
+    /// assert_eq!(tc.to_string(), "00:00:01:00");
+    /// assert_eq!(error, SignedMillisecond::from_millis_i128(-10));
+    ///
+    /// let ms = Millisecond::from_minutes(1);
+    /// let (tc, _) = ms.to_timecode(FrameRate::Fps29_97Drop).unwrap();
+    /// assert_eq!(tc.to_string(), "00:00:59;28");
+    /// ```
+    pub fn to_timecode(&self, rate: FrameRate) -> Result<(Timecode, SignedMillisecond), Error> {
+        let nanos = self.as_nanos();
+        let (num, den) = rate.ratio();
+        let unit = 2 * den * Unit::Seconds.nanos();
+        let frame = nanos
+            .checked_mul(2 * num)
+            .map(|x| x / unit + (x % unit >= unit / 2) as u128)
+            .filter(|x| *x < rate.frames_per_day())
+            .ok_or(Error::Overflow)?;
+
+        let start = rate.frame_nanos(frame) as i128;
+        let error = SignedMillisecond::from_nanos_i128(start - nanos as i128);
+        Ok((Timecode::from_frame(frame, rate), error))
+    }
+
+    /// Parses a SMPTE timecode at `rate` into the start of its frame, to the nearest
+    /// nanosecond. Drop-frame rates require `;` before the frames and the others `:`.
+    /// Components out of their range, including the frame numbers skipped by drop-frame
+    /// rates, are rejected with `ParseErrorKind::ComponentOutOfRange`.
+    /// ### example
+    /// ```rust
+    /// use millisecond::{FrameRate, Millisecond, ParseErrorKind};
+    /// let ms = Millisecond::from_timecode("00:00:01:12", FrameRate::Fps24).unwrap();
+    /// assert_eq!(ms, Millisecond::from_millis(1_500));
+    ///
+    /// let err = Millisecond::from_timecode("00:01:00;00", FrameRate::Fps29_97Drop).unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::ComponentOutOfRange);
+    /// assert_eq!(err.offset(), 9);
+    /// ```
+    pub fn from_timecode(input: &str, rate: FrameRate) -> Result<Self, ParseError> {
+        let mut s = Scanner::new(input);
+        s.skip_whitespace();
+        if s.is_end() {
+            return Err(ParseError::new(ParseErrorKind::Empty, s.pos()));
+        }
+        let hours = component(&mut s, 23)?;
+        expect(&mut s, ":")?;
+        let minutes = component(&mut s, 59)?;
+        expect(&mut s, ":")?;
+        let seconds = component(&mut s, 59)?;
+        expect(&mut s, if rate.is_drop_frame() { ";" } else { ":" })?;
+        let start = s.pos();
+        let frames = component(&mut s, rate.timebase() - 1)?;
+        if seconds == 0 && minutes % 10 != 0 && (frames as u128) < rate.dropped_frames() {
+            return Err(ParseError::new(ParseErrorKind::ComponentOutOfRange, start));
+        }
+        s.skip_whitespace();
+        if !s.is_end() {
+            return Err(ParseError::new(ParseErrorKind::UnexpectedToken, s.pos()));
+        }
+
+        let tc = Timecode {
+            hours,
+            minutes,
+            seconds,
+            frames,
+            drop_frame: rate.is_drop_frame(),
+        };
+        // a day of frames always fits into a `Millisecond`
+        Ok(Millisecond::from_nanos(rate.frame_nanos(tc.frame(rate))))
+    }
+}
+
+/// Consumes a number which is at most `max`.
+fn component(s: &mut Scanner, max: u8) -> Result<u8, ParseError> {
+    let start = s.pos();
+    let value = match s.take_while(|c| c.is_ascii_digit()) {
+        "" => return Err(ParseError::new(ParseErrorKind::InvalidNumber, start)),
+        digits => digits.parse::<u128>().unwrap_or(u128::MAX),
+    };
+    if value > max as u128 {
+        return Err(ParseError::new(ParseErrorKind::ComponentOutOfRange, start));
+    }
+    Ok(value as u8)
+}
+
+fn expect(s: &mut Scanner, text: &str) -> Result<(), ParseError> {
+    if !s.eat(text) {
+        return Err(ParseError::new(ParseErrorKind::UnexpectedToken, s.pos()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn should_format_timecode() {
+        let cases = [
+            (FrameRate::Fps24, 0, "00:00:00:00"),
+            (FrameRate::Fps24, 41_666_667, "00:00:00:01"),
+            (FrameRate::Fps24, 1_500_000_000, "00:00:01:12"),
+            (FrameRate::Fps25, 3_723_040_000_000, "01:02:03:01"),
+            (FrameRate::Fps30, 59_966_666_667, "00:00:59:29"),
+            (FrameRate::Fps29_97, 1_001_000_000, "00:00:01:00"),
+            (FrameRate::Fps29_97Drop, 60_060_000_000, "00:01:00;02"),
+            (FrameRate::Fps29_97Drop, 599_999_400_000, "00:10:00;00"),
+            (FrameRate::Fps59_94, 1_001_000_000, "00:00:01:00"),
+            (FrameRate::Fps59_94Drop, 60_060_000_000, "00:01:00;04"),
+            (FrameRate::Fps59_94Drop, 599_999_400_000, "00:10:00;00"),
+        ];
+        for (rate, nanos, text) in cases {
+            let ms = Millisecond::from_nanos(nanos);
+            let (tc, error) = ms.to_timecode(rate).unwrap();
+            assert_eq!(tc.to_string(), text, "{nanos}");
+            assert_eq!(error, SignedMillisecond::default(), "{nanos}");
+            assert_eq!(Millisecond::from_timecode(text, rate), Ok(ms), "{text}");
+        }
+    }
+    #[test]
+    fn should_report_rounding_error() {
+        let cases = [
+            (FrameRate::Fps25, 19_999_999, 0, -19_999_999),
+            (FrameRate::Fps25, 20_000_000, 1, 20_000_000),
+            (FrameRate::Fps24, 50_000_000, 1, -8_333_333),
+            (FrameRate::Fps29_97, 1_000_000_000, 30, 1_000_000),
+        ];
+        for (rate, nanos, frame, error) in cases {
+            let ms = Millisecond::from_nanos(nanos);
+            let (tc, err) = ms.to_timecode(rate).unwrap();
+            assert_eq!(tc, Timecode::from_frame(frame, rate), "{nanos}");
+            assert_eq!(err, SignedMillisecond::from_nanos_i128(error), "{nanos}");
+        }
+    }
+    #[test]
+    fn should_round_trip_drop_frame() {
+        for rate in [FrameRate::Fps29_97Drop, FrameRate::Fps59_94Drop] {
+            for frame in (0..rate.frames_per_day()).step_by(997) {
+                let tc = Timecode::from_frame(frame, rate);
+                assert_eq!(tc.frame(rate), frame, "{tc}");
+                let ms = Millisecond::from_timecode(&tc.to_string(), rate).unwrap();
+                assert_eq!(ms.to_timecode(rate).unwrap().0, tc, "{tc}");
+            }
+        }
+    }
+    #[test]
+    fn should_limit_timecode_to_a_day() {
+        let last = Millisecond::from_timecode("23:59:59;29", FrameRate::Fps29_97Drop).unwrap();
+        let (tc, _) = last.to_timecode(FrameRate::Fps29_97Drop).unwrap();
+        assert_eq!(tc.to_string(), "23:59:59;29");
+        let next = last + Millisecond::from_millis(34);
+        assert_eq!(
+            next.to_timecode(FrameRate::Fps29_97Drop),
+            Err(Error::Overflow)
+        );
+        let ms = Millisecond::from_days(1);
+        assert_eq!(ms.to_timecode(FrameRate::Fps25), Err(Error::Overflow));
+        assert_eq!(
+            Millisecond::MAX.to_timecode(FrameRate::Fps24),
+            Err(Error::Overflow)
+        );
+    }
+    #[test]
+    fn should_reject_invalid_timecode() {
+        let cases = [
+            ("", FrameRate::Fps25, ParseErrorKind::Empty, 0),
+            (
+                "24:00:00:00",
+                FrameRate::Fps25,
+                ParseErrorKind::ComponentOutOfRange,
+                0,
+            ),
+            (
+                "00:60:00:00",
+                FrameRate::Fps25,
+                ParseErrorKind::ComponentOutOfRange,
+                3,
+            ),
+            (
+                "00:00:60:00",
+                FrameRate::Fps25,
+                ParseErrorKind::ComponentOutOfRange,
+                6,
+            ),
+            (
+                "00:00:00:25",
+                FrameRate::Fps25,
+                ParseErrorKind::ComponentOutOfRange,
+                9,
+            ),
+            (
+                "00:00:00:60",
+                FrameRate::Fps59_94,
+                ParseErrorKind::ComponentOutOfRange,
+                9,
+            ),
+            (
+                "00:00:00;00",
+                FrameRate::Fps25,
+                ParseErrorKind::UnexpectedToken,
+                8,
+            ),
+            (
+                "00:00:00:00",
+                FrameRate::Fps29_97Drop,
+                ParseErrorKind::UnexpectedToken,
+                8,
+            ),
+            (
+                "00:01:00;01",
+                FrameRate::Fps29_97Drop,
+                ParseErrorKind::ComponentOutOfRange,
+                9,
+            ),
+            (
+                "00:01:00;03",
+                FrameRate::Fps59_94Drop,
+                ParseErrorKind::ComponentOutOfRange,
+                9,
+            ),
+            (
+                "00:00:00",
+                FrameRate::Fps25,
+                ParseErrorKind::UnexpectedToken,
+                8,
+            ),
+            (
+                "00:00:00:",
+                FrameRate::Fps25,
+                ParseErrorKind::InvalidNumber,
+                9,
+            ),
+            (
+                "00:00:00:00:00",
+                FrameRate::Fps25,
+                ParseErrorKind::UnexpectedToken,
+                11,
+            ),
+        ];
+        for (k, rate, kind, offset) in cases {
+            assert_eq!(
+                Millisecond::from_timecode(k, rate),
+                Err(ParseError::new(kind, offset)),
+                "{k}"
+            );
+        }
+        let ms = Millisecond::from_timecode(" 00:10:00;00 ", FrameRate::Fps29_97Drop);
+        assert_eq!(ms, Ok(Millisecond::from_nanos(599_999_400_000)));
+    }
+}
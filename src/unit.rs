@@ -0,0 +1,28 @@
+/// The units a `Millisecond` is split into, from the largest to the smallest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Years,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl Unit {
+    /// Returns the length of the unit in nanoseconds, with a year of 365 days.
+    pub(crate) const fn nanos(self) -> u128 {
+        match self {
+            Unit::Years => 365 * Unit::Days.nanos(),
+            Unit::Days => 24 * Unit::Hours.nanos(),
+            Unit::Hours => 60 * Unit::Minutes.nanos(),
+            Unit::Minutes => 60 * Unit::Seconds.nanos(),
+            Unit::Seconds => 1_000_000_000,
+            Unit::Millis => 1_000_000,
+            Unit::Micros => 1_000,
+            Unit::Nanos => 1,
+        }
+    }
+}